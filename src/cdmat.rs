@@ -0,0 +1,509 @@
+extern crate alloc;
+use crate::complex::Complex;
+use crate::dmat::{checked_len, DMat};
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
+
+//dynamically sized complex matrix, the complex counterpart of DMat
+#[derive(Debug, PartialEq, Clone)]
+pub struct CDMat {
+    vals: Box<[Complex]>,
+    rows: usize,
+    cols: usize,
+}
+
+impl Index<usize> for CDMat {
+    type Output = [Complex];
+    fn index(&self, index: usize) -> &Self::Output {
+        let starting_idx = self.cols * index;
+        &self.vals[starting_idx..(starting_idx + self.cols)]
+    }
+}
+
+impl IndexMut<usize> for CDMat {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let starting_idx = self.cols * index;
+        &mut self.vals[starting_idx..(starting_idx + self.cols)]
+    }
+}
+
+impl<const R: usize, const C: usize, T: Into<Complex>> From<[[T; C]; R]> for CDMat {
+    fn from(value: [[T; C]; R]) -> Self {
+        let vec = value
+            .into_iter()
+            .flat_map(|row| row.map(|n| n.into()))
+            .collect::<Vec<Complex>>();
+
+        Self {
+            vals: vec.into_boxed_slice(),
+            rows: R,
+            cols: C,
+        }
+    }
+}
+
+impl From<&DMat> for CDMat {
+    fn from(value: &DMat) -> Self {
+        Self::generate(value.rows(), value.cols(), |row, col| {
+            Complex::from(value[row][col])
+        })
+    }
+}
+
+impl Add for &CDMat {
+    type Output = CDMat;
+    fn add(self, rhs: Self) -> Self::Output {
+        assert!(
+            (self.rows, self.cols) == (rhs.rows, rhs.cols),
+            "Attempted to add two matrices of different sizes"
+        );
+
+        Self::Output::generate(self.rows, self.cols, |row, col| {
+            self[row][col] + rhs[row][col]
+        })
+    }
+}
+
+impl Add<&Self> for CDMat {
+    type Output = Self;
+    fn add(mut self, rhs: &Self) -> Self::Output {
+        assert!(
+            (self.rows, self.cols) == (rhs.rows, rhs.cols),
+            "Attempted to add two matrices of different sizes"
+        );
+        self.mutate(|val, row, col| val + rhs[row][col]);
+        self
+    }
+}
+
+impl Add<Self> for CDMat {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Add::<&Self>::add(self, &rhs)
+    }
+}
+
+impl AddAssign for CDMat {
+    fn add_assign(&mut self, rhs: Self) {
+        *self += &rhs;
+    }
+}
+
+impl AddAssign<&Self> for CDMat {
+    fn add_assign(&mut self, rhs: &Self) {
+        assert!(
+            (self.rows, self.cols) == (rhs.rows, rhs.cols),
+            "Attempted to add two matrices of different sizes"
+        );
+        self.mutate(|val, row, col| val + rhs[row][col]);
+    }
+}
+
+impl Sub for &CDMat {
+    type Output = CDMat;
+    fn sub(self, rhs: Self) -> Self::Output {
+        assert!(
+            (self.rows, self.cols) == (rhs.rows, rhs.cols),
+            "Attempted to subtract two matrices of different sizes"
+        );
+
+        Self::Output::generate(self.rows, self.cols, |row, col| {
+            self[row][col] - rhs[row][col]
+        })
+    }
+}
+
+impl Sub<&Self> for CDMat {
+    type Output = Self;
+    fn sub(mut self, rhs: &Self) -> Self::Output {
+        assert!(
+            (self.rows, self.cols) == (rhs.rows, rhs.cols),
+            "Attempted to subtract two matrices of different sizes"
+        );
+        self.mutate(|val, row, col| val - rhs[row][col]);
+        self
+    }
+}
+
+impl Sub<Self> for CDMat {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Sub::<&Self>::sub(self, &rhs)
+    }
+}
+
+impl SubAssign for CDMat {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self -= &rhs;
+    }
+}
+
+impl SubAssign<&Self> for CDMat {
+    fn sub_assign(&mut self, rhs: &Self) {
+        assert!(
+            (self.rows, self.cols) == (rhs.rows, rhs.cols),
+            "Attempted to subtract two matrices of different sizes"
+        );
+        self.mutate(|val, row, col| val - rhs[row][col]);
+    }
+}
+
+impl<T: Into<Complex>> Mul<T> for CDMat {
+    type Output = Self;
+    fn mul(mut self, scalar: T) -> Self::Output {
+        let scalar: Complex = scalar.into();
+        self.mutate(|val, _, _| val * scalar);
+        self
+    }
+}
+
+impl<T: Into<Complex>> Mul<T> for &CDMat {
+    type Output = CDMat;
+    fn mul(self, scalar: T) -> Self::Output {
+        let scalar: Complex = scalar.into();
+        self.map(|val| val * scalar)
+    }
+}
+
+impl<T: Into<Complex>> MulAssign<T> for CDMat {
+    fn mul_assign(&mut self, scalar: T) {
+        let scalar: Complex = scalar.into();
+        self.mutate(|val, _, _| val * scalar);
+    }
+}
+
+impl Mul for &CDMat {
+    type Output = CDMat;
+    fn mul(self, rhs: Self) -> Self::Output {
+        assert!(
+            self.cols == rhs.rows,
+            "Attempted to multiply two non-commutative matrices"
+        );
+
+        Self::Output::generate(self.rows, rhs.cols, |row, col| {
+            self[row]
+                .iter()
+                .enumerate()
+                .fold(Complex::ZERO, |acc, (index, n)| acc + *n * rhs[index][col])
+        })
+    }
+}
+
+impl Mul<&Self> for CDMat {
+    type Output = Self;
+    fn mul(self, rhs: &Self) -> Self::Output {
+        <&CDMat as Mul>::mul(&self, rhs)
+    }
+}
+
+impl Mul<Self> for CDMat {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        <&CDMat as Mul>::mul(&self, &rhs)
+    }
+}
+
+impl<T: Into<Complex>> Div<T> for CDMat {
+    type Output = Self;
+    fn div(mut self, scalar: T) -> Self::Output {
+        let scalar: Complex = scalar.into();
+        self.mutate(|val, _, _| val / scalar);
+        self
+    }
+}
+
+impl<T: Into<Complex>> Div<T> for &CDMat {
+    type Output = CDMat;
+    fn div(self, scalar: T) -> Self::Output {
+        let scalar: Complex = scalar.into();
+        self.map(|val| val / scalar)
+    }
+}
+
+impl<T: Into<Complex>> DivAssign<T> for CDMat {
+    fn div_assign(&mut self, scalar: T) {
+        let scalar: Complex = scalar.into();
+        self.mutate(|val, _, _| val / scalar);
+    }
+}
+
+impl Neg for CDMat {
+    type Output = Self;
+    fn neg(mut self) -> Self::Output {
+        self.mutate(|val, _, _| -val);
+        self
+    }
+}
+
+impl Neg for &CDMat {
+    type Output = CDMat;
+    fn neg(self) -> Self::Output {
+        self.map(|val| -val)
+    }
+}
+
+impl CDMat {
+    #[must_use]
+    pub fn zero(rows: usize, cols: usize) -> Self {
+        Self {
+            vals: vec![Complex::ZERO; checked_len(rows, cols)].into_boxed_slice(),
+            rows,
+            cols,
+        }
+    }
+
+    #[must_use]
+    pub fn generate<F: Fn(usize, usize) -> Complex>(rows: usize, cols: usize, f: F) -> Self {
+        let vec: Vec<Complex> = (0..checked_len(rows, cols))
+            .map(|index| f(index / cols, index % cols))
+            .collect();
+
+        Self {
+            vals: vec.into_boxed_slice(),
+            rows,
+            cols,
+        }
+    }
+
+    //builds a complex matrix from its real and imaginary parts
+    #[must_use]
+    pub fn from_parts(re: &DMat, im: &DMat) -> Self {
+        assert!(
+            (re.rows(), re.cols()) == (im.rows(), im.cols()),
+            "Attempted to build a complex matrix from parts of different sizes"
+        );
+        Self::generate(re.rows(), re.cols(), |row, col| {
+            Complex::new(re[row][col], im[row][col])
+        })
+    }
+
+    #[must_use]
+    pub fn identity(n: usize) -> Self {
+        Self::generate(n, n, |row, col| {
+            if row == col {
+                Complex::ONE
+            } else {
+                Complex::ZERO
+            }
+        })
+    }
+
+    pub fn mutate<F: Fn(Complex, usize, usize) -> Complex>(&mut self, f: F) {
+        for (index, val) in self.vals.iter_mut().enumerate() {
+            *val = f(*val, index / self.cols, index % self.cols);
+        }
+    }
+
+    #[must_use]
+    pub fn map<F: Fn(Complex) -> Complex>(&self, f: F) -> Self {
+        Self::generate(self.rows, self.cols, |row, col| f(self[row][col]))
+    }
+
+    #[must_use]
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    #[must_use]
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    #[must_use]
+    pub fn re(&self) -> DMat {
+        DMat::generate(self.rows, self.cols, |row, col| self[row][col].re)
+    }
+
+    #[must_use]
+    pub fn im(&self) -> DMat {
+        DMat::generate(self.rows, self.cols, |row, col| self[row][col].im)
+    }
+
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        Self::generate(self.cols, self.rows, |row, col| self[col][row])
+    }
+
+    #[must_use]
+    pub fn conjugate(&self) -> Self {
+        self.map(|val| val.conjugate())
+    }
+
+    //conjugate transpose
+    #[must_use]
+    pub fn adjoint(&self) -> Self {
+        Self::generate(self.cols, self.rows, |row, col| self[col][row].conjugate())
+    }
+
+    #[must_use]
+    pub fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+        (self.rows, self.cols) == (other.rows, other.cols)
+            && self
+                .vals
+                .iter()
+                .zip(other.vals.iter())
+                .all(|(a, b)| a.approx_eq(b, tolerance))
+    }
+
+    #[must_use]
+    pub fn is_hermitian(&self) -> bool {
+        //non-square matrices cannot be hermitian
+        if self.rows != self.cols {
+            return false;
+        }
+        *self == self.adjoint()
+    }
+
+    #[must_use]
+    pub fn is_hermitian_within(&self, tolerance: f64) -> bool {
+        self.rows == self.cols && self.approx_eq(&self.adjoint(), tolerance)
+    }
+
+    #[must_use]
+    pub fn is_unitary(&self) -> bool {
+        //non-square matrices cannot be unitary
+        if self.rows != self.cols {
+            return false;
+        }
+        self * &self.adjoint() == Self::identity(self.rows)
+    }
+
+    #[must_use]
+    pub fn is_unitary_within(&self, tolerance: f64) -> bool {
+        self.rows == self.cols
+            && (self * &self.adjoint()).approx_eq(&Self::identity(self.rows), tolerance)
+    }
+
+    #[must_use]
+    pub fn to_determinant(mut self) -> Complex {
+        //determinant is undefined for non-square matrices
+        assert!(
+            self.rows == self.cols,
+            "Attempted to take determinant of non-square matrix"
+        );
+
+        let size = self.rows;
+        let mut determinant = Complex::ONE;
+
+        for k in 0..size {
+            //find k-th pivot, the largest element by magnitude on or below the diagonal
+            let pivot = (k..size).fold(k, |acc, index| {
+                if self[index][k].abs() > self[acc][k].abs() {
+                    index
+                } else {
+                    acc
+                }
+            });
+
+            if self[pivot][k] == Complex::ZERO {
+                //matrix is singular
+                return Complex::ZERO;
+            }
+
+            //swap rows, flip determinant sign
+            if k != pivot {
+                self.swap_rows(k, pivot);
+                determinant = -determinant;
+            }
+
+            for i in k + 1..size {
+                let c = self[i][k] / self[k][k];
+                for j in k + 1..size {
+                    let value = self[k][j] * c;
+                    self[i][j] -= value;
+                }
+            }
+
+            determinant *= self[k][k];
+        }
+
+        determinant
+    }
+
+    #[must_use]
+    pub fn determinant(&self) -> Complex {
+        self.clone().to_determinant()
+    }
+
+    //returns None if the matrix is singular
+    #[must_use]
+    pub fn to_inverse(mut self) -> Option<Self> {
+        //inverse is undefined for non-square matrices
+        assert!(
+            self.rows == self.cols,
+            "Attempted to take inverse of non-square matrix"
+        );
+
+        //gauss-jordan elimination with partial pivoting on [self | identity]
+        let size = self.rows;
+        let mut inverse = Self::identity(size);
+
+        for k in 0..size {
+            let pivot = (k..size).fold(k, |acc, index| {
+                if self[index][k].abs() > self[acc][k].abs() {
+                    index
+                } else {
+                    acc
+                }
+            });
+
+            if self[pivot][k] == Complex::ZERO {
+                //matrix is singular
+                return None;
+            }
+
+            self.swap_rows(k, pivot);
+            inverse.swap_rows(k, pivot);
+
+            //divide current row by pivot element
+            let divisor = self[k][k];
+            for col in 0..size {
+                self[k][col] /= divisor;
+                inverse[k][col] /= divisor;
+            }
+
+            //eliminate the current column from every other row
+            for row in 0..size {
+                if row != k {
+                    let factor = self[row][k];
+                    for col in 0..size {
+                        let (value, inverse_value) =
+                            (self[k][col] * factor, inverse[k][col] * factor);
+                        self[row][col] -= value;
+                        inverse[row][col] -= inverse_value;
+                    }
+                }
+            }
+        }
+
+        Some(inverse)
+    }
+
+    #[must_use]
+    pub fn inverse(&self) -> Option<Self> {
+        self.clone().to_inverse()
+    }
+
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        if a != b {
+            for col in 0..self.cols {
+                self.vals.swap(a * self.cols + col, b * self.cols + col);
+            }
+        }
+    }
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __cdmat_macro {
+    ( $( $($e: expr),* );* ) => {
+        CDMat::from([ $([ $($e),* ]),* ])
+    };
+}
+
+#[doc(inline)]
+pub use __cdmat_macro as cdmat;
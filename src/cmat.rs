@@ -0,0 +1,322 @@
+use crate::complex::Complex;
+use crate::mat::Mat;
+use core::ops::{
+    Add, AddAssign, Deref, DerefMut, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign,
+};
+
+//statically sized complex matrix, the complex counterpart of Mat
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct CMat<const R: usize, const C: usize>([[Complex; C]; R]);
+
+impl<const R: usize, const C: usize> Deref for CMat<R, C> {
+    type Target = [[Complex; C]; R];
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<const R: usize, const C: usize> DerefMut for CMat<R, C> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<const R: usize, const C: usize, T: Into<Complex>> From<[[T; C]; R]> for CMat<R, C> {
+    fn from(value: [[T; C]; R]) -> Self {
+        Self(value.map(|row| row.map(|n| n.into())))
+    }
+}
+
+impl<const R: usize, const C: usize> From<Mat<R, C>> for CMat<R, C> {
+    fn from(value: Mat<R, C>) -> Self {
+        Self::from(*value)
+    }
+}
+
+impl<const R: usize, const C: usize> Add for CMat<R, C> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::generate(|row, col| self[row][col] + rhs[row][col])
+    }
+}
+
+impl<const R: usize, const C: usize> AddAssign for CMat<R, C> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const R: usize, const C: usize> Sub for CMat<R, C> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::generate(|row, col| self[row][col] - rhs[row][col])
+    }
+}
+
+impl<const R: usize, const C: usize> SubAssign for CMat<R, C> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const R: usize, const C: usize, T: Into<Complex>> Mul<T> for CMat<R, C> {
+    type Output = Self;
+    fn mul(self, scalar: T) -> Self::Output {
+        let scalar: Complex = scalar.into();
+        self.map(|n| n * scalar)
+    }
+}
+
+impl<const R: usize, const C: usize, T: Into<Complex>> MulAssign<T> for CMat<R, C> {
+    fn mul_assign(&mut self, scalar: T) {
+        *self = *self * scalar;
+    }
+}
+
+impl<const R: usize, const C: usize> Neg for CMat<R, C> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        self.map(|n| -n)
+    }
+}
+
+impl<const R: usize, const C: usize, const C2: usize> Mul<CMat<C, C2>> for CMat<R, C> {
+    type Output = CMat<R, C2>;
+
+    fn mul(self, rhs: CMat<C, C2>) -> Self::Output {
+        Self::Output::generate(|r, c| {
+            self[r]
+                .iter()
+                .enumerate()
+                .fold(Complex::ZERO, |acc, (index, n)| acc + *n * rhs[index][c])
+        })
+    }
+}
+
+impl<const R: usize, const C: usize, T: Into<Complex>> Div<T> for CMat<R, C> {
+    type Output = Self;
+    fn div(self, scalar: T) -> Self::Output {
+        let scalar: Complex = scalar.into();
+        self.map(|n| n / scalar)
+    }
+}
+
+impl<const R: usize, const C: usize, T: Into<Complex>> DivAssign<T> for CMat<R, C> {
+    fn div_assign(&mut self, scalar: T) {
+        *self = *self / scalar;
+    }
+}
+
+impl<const R: usize, const C: usize> CMat<R, C> {
+    #[must_use]
+    pub const fn zero() -> Self {
+        Self([[Complex::ZERO; C]; R])
+    }
+
+    pub fn generate<F: Fn(usize, usize) -> Complex>(f: F) -> Self {
+        let mut mat = Self::zero();
+
+        for (row_index, row) in mat.iter_mut().enumerate() {
+            for (column_index, n) in row.iter_mut().enumerate() {
+                *n = f(row_index, column_index);
+            }
+        }
+
+        mat
+    }
+
+    //builds a complex matrix from its real and imaginary parts
+    #[must_use]
+    pub fn from_parts(re: &Mat<R, C>, im: &Mat<R, C>) -> Self {
+        Self::generate(|row, col| Complex::new(re[row][col], im[row][col]))
+    }
+
+    #[must_use]
+    pub fn re(&self) -> Mat<R, C> {
+        Mat::generate(|row, col| self[row][col].re)
+    }
+
+    #[must_use]
+    pub fn im(&self) -> Mat<R, C> {
+        Mat::generate(|row, col| self[row][col].im)
+    }
+
+    #[must_use]
+    pub fn map<F: Fn(Complex) -> Complex>(&self, f: F) -> Self {
+        Self::generate(|row, col| f(self[row][col]))
+    }
+
+    #[must_use]
+    pub fn transpose(&self) -> CMat<C, R> {
+        CMat::<C, R>::generate(|row, column| self[column][row])
+    }
+
+    #[must_use]
+    pub fn conjugate(&self) -> Self {
+        self.map(|n| n.conjugate())
+    }
+
+    //conjugate transpose
+    #[must_use]
+    pub fn adjoint(&self) -> CMat<C, R> {
+        CMat::<C, R>::generate(|row, column| self[column][row].conjugate())
+    }
+
+    #[must_use]
+    pub fn row(&self, row: usize) -> [Complex; C] {
+        self[row]
+    }
+
+    #[must_use]
+    pub fn col(&self, col: usize) -> [Complex; R] {
+        (**self).map(|row| row[col])
+    }
+
+    #[must_use]
+    pub fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+        self.iter()
+            .flatten()
+            .zip(other.iter().flatten())
+            .all(|(a, b)| a.approx_eq(b, tolerance))
+    }
+}
+
+//functions/operations exclusive to square matrices
+
+impl<const N: usize> CMat<N, N> {
+    #[must_use]
+    pub fn identity() -> Self {
+        Self::generate(|row, column| {
+            if row == column {
+                Complex::ONE
+            } else {
+                Complex::ZERO
+            }
+        })
+    }
+
+    #[must_use]
+    pub fn is_hermitian(&self) -> bool {
+        *self == self.adjoint()
+    }
+
+    #[must_use]
+    pub fn is_hermitian_within(&self, tolerance: f64) -> bool {
+        self.approx_eq(&self.adjoint(), tolerance)
+    }
+
+    #[must_use]
+    pub fn is_unitary(&self) -> bool {
+        *self * self.adjoint() == Self::identity()
+    }
+
+    #[must_use]
+    pub fn is_unitary_within(&self, tolerance: f64) -> bool {
+        (*self * self.adjoint()).approx_eq(&Self::identity(), tolerance)
+    }
+
+    #[must_use]
+    pub fn determinant(&self) -> Complex {
+        //gaussian elimination with partial pivoting, as in Mat::determinant
+        let mut reduced = *self;
+        let mut determinant = Complex::ONE;
+
+        for k in 0..N {
+            //find k-th pivot, the largest element by magnitude on or below the diagonal
+            let pivot = (k..N).fold(k, |acc, index| {
+                if reduced[index][k].abs() > reduced[acc][k].abs() {
+                    index
+                } else {
+                    acc
+                }
+            });
+
+            if reduced[pivot][k] == Complex::ZERO {
+                //matrix is singular
+                return Complex::ZERO;
+            }
+
+            //swap rows, flip determinant sign
+            if k != pivot {
+                reduced.swap(k, pivot);
+                determinant = -determinant;
+            }
+
+            for i in k + 1..N {
+                let c = reduced[i][k] / reduced[k][k];
+                for j in k + 1..N {
+                    let value = reduced[k][j] * c;
+                    reduced[i][j] -= value;
+                }
+            }
+
+            determinant *= reduced[k][k];
+        }
+
+        determinant
+    }
+
+    #[must_use]
+    pub fn inverse(&self) -> Option<Self> {
+        //gauss-jordan elimination with partial pivoting on [self | identity]
+        let mut reduced = *self;
+        let mut inverse = Self::identity();
+
+        for k in 0..N {
+            let pivot = (k..N).fold(k, |acc, index| {
+                if reduced[index][k].abs() > reduced[acc][k].abs() {
+                    index
+                } else {
+                    acc
+                }
+            });
+
+            if reduced[pivot][k] == Complex::ZERO {
+                //matrix is singular
+                return None;
+            }
+
+            reduced.swap(k, pivot);
+            inverse.swap(k, pivot);
+
+            //divide current row by pivot element
+            let divisor = reduced[k][k];
+            for col in 0..N {
+                reduced[k][col] /= divisor;
+                inverse[k][col] /= divisor;
+            }
+
+            //eliminate the current column from every other row
+            for row in 0..N {
+                if row != k {
+                    let factor = reduced[row][k];
+                    for col in 0..N {
+                        let (reduced_value, inverse_value) =
+                            (reduced[k][col] * factor, inverse[k][col] * factor);
+                        reduced[row][col] -= reduced_value;
+                        inverse[row][col] -= inverse_value;
+                    }
+                }
+            }
+        }
+
+        Some(inverse)
+    }
+}
+
+impl<const N: usize> MulAssign for CMat<N, N> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __cmat_macro {
+    ( $( $($e: expr),* );* ) => {
+        CMat::from([ $([ $($e),* ]),* ])
+    };
+}
+
+#[doc(inline)]
+pub use __cmat_macro as cmat;
@@ -0,0 +1,165 @@
+use crate::f64_abs;
+use core::fmt::Display;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+//complex number re + im * i, the scalar type of CMat and CDMat
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct Complex {
+    pub re: f64,
+    pub im: f64,
+}
+
+impl Display for Complex {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.im == 0.0 {
+            write!(f, "{}", self.re)
+        } else if self.re == 0.0 {
+            write!(f, "{}i", self.im)
+        } else {
+            let sign = if self.im.is_sign_negative() { '-' } else { '+' };
+            write!(f, "{}{sign}{}i", self.re, f64_abs(self.im))
+        }
+    }
+}
+
+impl<T: Into<f64>> From<T> for Complex {
+    fn from(value: T) -> Self {
+        Self::new(value.into(), 0.0)
+    }
+}
+
+impl Add for Complex {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl AddAssign for Complex {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Complex {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl SubAssign for Complex {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul for Complex {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl MulAssign for Complex {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl Div for Complex {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self::Output {
+        //smith's algorithm, avoiding overflow when squaring large denominators
+        if f64_abs(rhs.re) >= f64_abs(rhs.im) {
+            let ratio = rhs.im / rhs.re;
+            let denominator = rhs.re + rhs.im * ratio;
+            Self::new(
+                (self.re + self.im * ratio) / denominator,
+                (self.im - self.re * ratio) / denominator,
+            )
+        } else {
+            let ratio = rhs.re / rhs.im;
+            let denominator = rhs.re * ratio + rhs.im;
+            Self::new(
+                (self.re * ratio + self.im) / denominator,
+                (self.im * ratio - self.re) / denominator,
+            )
+        }
+    }
+}
+
+impl DivAssign for Complex {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl Neg for Complex {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self::new(-self.re, -self.im)
+    }
+}
+
+impl Complex {
+    pub const ZERO: Self = Self::new(0.0, 0.0);
+    pub const ONE: Self = Self::new(1.0, 0.0);
+    pub const I: Self = Self::new(0.0, 1.0);
+
+    #[must_use]
+    pub const fn new(re: f64, im: f64) -> Self {
+        Self { re, im }
+    }
+
+    #[must_use]
+    pub fn from_polar(magnitude: f64, angle: f64) -> Self {
+        Self::new(magnitude * libm::cos(angle), magnitude * libm::sin(angle))
+    }
+
+    #[must_use]
+    pub fn conjugate(&self) -> Self {
+        Self::new(self.re, -self.im)
+    }
+
+    #[must_use]
+    pub fn norm_squared(&self) -> f64 {
+        self.re * self.re + self.im * self.im
+    }
+
+    //magnitude |z|
+    #[must_use]
+    pub fn abs(&self) -> f64 {
+        libm::hypot(self.re, self.im)
+    }
+
+    //argument in (-pi, pi]
+    #[must_use]
+    pub fn arg(&self) -> f64 {
+        libm::atan2(self.im, self.re)
+    }
+
+    #[must_use]
+    pub fn recip(&self) -> Self {
+        Self::ONE / *self
+    }
+
+    #[must_use]
+    pub fn exp(&self) -> Self {
+        Self::from_polar(libm::exp(self.re), self.im)
+    }
+
+    //principal square root
+    #[must_use]
+    pub fn sqrt(&self) -> Self {
+        Self::from_polar(libm::sqrt(self.abs()), self.arg() / 2.0)
+    }
+
+    #[must_use]
+    pub fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+        (*self - *other).abs() <= tolerance
+    }
+}
@@ -0,0 +1,367 @@
+extern crate alloc;
+use crate::dmat::DMat;
+use crate::mat::Mat;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{Display, Write};
+
+//reading and writing of delimited text (CSV, TSV, whitespace separated columns)
+//blank lines and lines starting with '#' are skipped, fields may be surrounded by double quotes
+
+const COMMENT: char = '#';
+const QUOTE: char = '"';
+
+//rows and columns are 1-based, rows counting lines of the input (including header, comments and blank lines)
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum DelimitedError {
+    //the input holds no data rows
+    Empty,
+    InvalidValue {
+        row: usize,
+        column: usize,
+    },
+    UnterminatedQuote {
+        row: usize,
+        column: usize,
+    },
+    //a row holding a different number of fields than the first data row,
+    //column being its first extra field or the first missing one
+    RaggedRow {
+        row: usize,
+        column: usize,
+        expected: usize,
+        found: usize,
+    },
+    //the input does not match the dimensions of the Mat being read
+    WrongDimensions {
+        expected: (usize, usize),
+        found: (usize, usize),
+    },
+}
+
+impl Display for DelimitedError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "no data rows found"),
+            Self::InvalidValue { row, column } => {
+                write!(f, "invalid value on row {row}, column {column}")
+            }
+            Self::UnterminatedQuote { row, column } => {
+                write!(f, "unterminated quote on row {row}, column {column}")
+            }
+            Self::RaggedRow {
+                row,
+                column,
+                expected,
+                found,
+            } => write!(
+                f,
+                "expected {expected} columns on row {row}, found {found} (from column {column})"
+            ),
+            Self::WrongDimensions { expected, found } => write!(
+                f,
+                "expected a {}x{} matrix, found {}x{}",
+                expected.0, expected.1, found.0, found.1
+            ),
+        }
+    }
+}
+
+impl core::error::Error for DelimitedError {}
+
+//splits a line into fields, removing surrounding whitespace and quotes
+//a whitespace delimiter treats any run of whitespace as a single separator
+fn split_fields(line: &str, delimiter: char, row: usize) -> Result<Vec<String>, DelimitedError> {
+    let is_delimiter = |c: char| c == delimiter || (delimiter.is_whitespace() && c.is_whitespace());
+    let is_padding = |c: &char| c.is_whitespace() && !is_delimiter(*c);
+    let mut fields = Vec::new();
+    let mut chars = line.trim().chars().peekable();
+
+    while chars.peek().is_some() || fields.is_empty() {
+        while chars.next_if(is_padding).is_some() {}
+
+        let mut field = String::new();
+        if chars.next_if_eq(&QUOTE).is_some() {
+            //a doubled quote inside a quoted field is an escaped quote
+            loop {
+                match chars.next() {
+                    Some(QUOTE) if chars.next_if_eq(&QUOTE).is_none() => break,
+                    Some(c) => field.push(c),
+                    None => {
+                        return Err(DelimitedError::UnterminatedQuote {
+                            row,
+                            column: fields.len() + 1,
+                        })
+                    }
+                }
+            }
+            while chars.next_if(is_padding).is_some() {}
+        } else {
+            while let Some(c) = chars.next_if(|&c| !is_delimiter(c)) {
+                field.push(c);
+            }
+            field.truncate(field.trim_end().len());
+        }
+
+        fields.push(field);
+        match chars.next() {
+            Some(c) if is_delimiter(c) => {
+                if delimiter.is_whitespace() {
+                    while chars.next_if(|&c| is_delimiter(c)).is_some() {}
+                } else if chars.peek().is_none() {
+                    //a trailing delimiter ends an empty last field
+                    fields.push(String::new());
+                }
+            }
+            Some(_) => {
+                return Err(DelimitedError::InvalidValue {
+                    row,
+                    column: fields.len(),
+                })
+            }
+            None => break,
+        }
+    }
+
+    Ok(fields)
+}
+
+//parses the data rows, returning the header fields if requested
+fn parse(
+    input: &str,
+    delimiter: char,
+    has_header: bool,
+) -> Result<(Option<Vec<String>>, DMat), DelimitedError> {
+    let mut lines = input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line))
+        .filter(|(_, line)| {
+            let line = line.trim();
+            !line.is_empty() && !line.starts_with(COMMENT)
+        });
+
+    let header = if has_header {
+        let (row, line) = lines.next().ok_or(DelimitedError::Empty)?;
+        Some(split_fields(line, delimiter, row)?)
+    } else {
+        None
+    };
+
+    let mut vals = Vec::new();
+    let mut rows = 0;
+    let mut cols = 0;
+
+    for (row, line) in lines {
+        let fields = split_fields(line, delimiter, row)?;
+        if rows == 0 {
+            cols = fields.len();
+        } else if fields.len() != cols {
+            return Err(DelimitedError::RaggedRow {
+                row,
+                column: cols.min(fields.len()) + 1,
+                expected: cols,
+                found: fields.len(),
+            });
+        }
+
+        for (index, field) in fields.iter().enumerate() {
+            let value = field.parse().map_err(|_| DelimitedError::InvalidValue {
+                row,
+                column: index + 1,
+            })?;
+            vals.push(value);
+        }
+        rows += 1;
+    }
+
+    if rows == 0 {
+        return Err(DelimitedError::Empty);
+    }
+
+    Ok((header, DMat::from_vec(rows, cols, vals)))
+}
+
+fn write_field<W: Write>(output: &mut W, field: &str, delimiter: char) -> core::fmt::Result {
+    if field.contains([delimiter, QUOTE, COMMENT, '\n', '\r']) || field.trim() != field {
+        output.write_char(QUOTE)?;
+        for c in field.chars() {
+            if c == QUOTE {
+                output.write_char(QUOTE)?;
+            }
+            output.write_char(c)?;
+        }
+        output.write_char(QUOTE)
+    } else {
+        output.write_str(field)
+    }
+}
+
+fn write_rows<'a, W: Write, I: Iterator<Item = &'a [f64]>>(
+    output: &mut W,
+    header: Option<&[&str]>,
+    rows: I,
+    delimiter: char,
+    precision: Option<usize>,
+) -> core::fmt::Result {
+    if let Some(header) = header {
+        for (index, field) in header.iter().enumerate() {
+            if index > 0 {
+                output.write_char(delimiter)?;
+            }
+            write_field(output, field, delimiter)?;
+        }
+        writeln!(output)?;
+    }
+
+    for row in rows {
+        for (index, value) in row.iter().enumerate() {
+            if index > 0 {
+                output.write_char(delimiter)?;
+            }
+            match precision {
+                Some(precision) => write!(output, "{value:.precision$}")?,
+                None => write!(output, "{value}")?,
+            }
+        }
+        writeln!(output)?;
+    }
+
+    Ok(())
+}
+
+impl DMat {
+    /// # Errors
+    ///
+    /// Returns a `DelimitedError` if a field is not a number, rows hold different numbers
+    /// of fields or the input holds no rows.
+    pub fn from_delimited(input: &str, delimiter: char) -> Result<Self, DelimitedError> {
+        parse(input, delimiter, false).map(|(_, mat)| mat)
+    }
+
+    //the first row is a header of column names, which may hold a different number of fields
+    /// # Errors
+    ///
+    /// Returns a `DelimitedError` if a field is not a number, rows hold different numbers
+    /// of fields or the input holds no data rows.
+    pub fn from_delimited_with_header(
+        input: &str,
+        delimiter: char,
+    ) -> Result<(Vec<String>, Self), DelimitedError> {
+        parse(input, delimiter, true).map(|(header, mat)| (header.unwrap_or_default(), mat))
+    }
+
+    //values are written with the given number of decimal places,
+    //or with as many as needed to read them back exactly
+    /// # Errors
+    ///
+    /// Returns an error if writing to the output fails.
+    pub fn write_delimited<W: Write>(
+        &self,
+        output: &mut W,
+        delimiter: char,
+        precision: Option<usize>,
+    ) -> core::fmt::Result {
+        write_rows(output, None, self.row_iter(), delimiter, precision)
+    }
+
+    /// # Errors
+    ///
+    /// Returns an error if writing to the output fails.
+    pub fn write_delimited_with_header<W: Write>(
+        &self,
+        output: &mut W,
+        header: &[&str],
+        delimiter: char,
+        precision: Option<usize>,
+    ) -> core::fmt::Result {
+        assert!(
+            header.len() == self.cols(),
+            "Attempted to write a header with a different number of columns than the matrix"
+        );
+        write_rows(output, Some(header), self.row_iter(), delimiter, precision)
+    }
+
+    #[must_use]
+    pub fn to_delimited(&self, delimiter: char, precision: Option<usize>) -> String {
+        let mut output = String::new();
+        self.write_delimited(&mut output, delimiter, precision)
+            .expect("Writing to a String cannot fail");
+        output
+    }
+}
+
+impl<const R: usize, const C: usize> Mat<R, C> {
+    fn from_dmat(mat: &DMat) -> Result<Self, DelimitedError> {
+        if (mat.rows(), mat.cols()) != (R, C) {
+            return Err(DelimitedError::WrongDimensions {
+                expected: (R, C),
+                found: (mat.rows(), mat.cols()),
+            });
+        }
+        Ok(Self::generate(|row, col| mat[row][col]))
+    }
+
+    /// # Errors
+    ///
+    /// Returns a `DelimitedError` if the input is not valid delimited text or does not hold an `R`x`C` matrix.
+    pub fn from_delimited(input: &str, delimiter: char) -> Result<Self, DelimitedError> {
+        Self::from_dmat(&DMat::from_delimited(input, delimiter)?)
+    }
+
+    /// # Errors
+    ///
+    /// Returns a `DelimitedError` if the input is not valid delimited text or does not hold an `R`x`C` matrix.
+    pub fn from_delimited_with_header(
+        input: &str,
+        delimiter: char,
+    ) -> Result<(Vec<String>, Self), DelimitedError> {
+        let (header, mat) = DMat::from_delimited_with_header(input, delimiter)?;
+        Ok((header, Self::from_dmat(&mat)?))
+    }
+
+    /// # Errors
+    ///
+    /// Returns an error if writing to the output fails.
+    pub fn write_delimited<W: Write>(
+        &self,
+        output: &mut W,
+        delimiter: char,
+        precision: Option<usize>,
+    ) -> core::fmt::Result {
+        write_rows(
+            output,
+            None,
+            self.iter().map(|row| &row[..]),
+            delimiter,
+            precision,
+        )
+    }
+
+    /// # Errors
+    ///
+    /// Returns an error if writing to the output fails.
+    pub fn write_delimited_with_header<W: Write>(
+        &self,
+        output: &mut W,
+        header: &[&str; C],
+        delimiter: char,
+        precision: Option<usize>,
+    ) -> core::fmt::Result {
+        write_rows(
+            output,
+            Some(header),
+            self.iter().map(|row| &row[..]),
+            delimiter,
+            precision,
+        )
+    }
+
+    #[must_use]
+    pub fn to_delimited(&self, delimiter: char, precision: Option<usize>) -> String {
+        let mut output = String::new();
+        self.write_delimited(&mut output, delimiter, precision)
+            .expect("Writing to a String cannot fail");
+        output
+    }
+}
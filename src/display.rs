@@ -0,0 +1,654 @@
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "alloc")]
+use crate::cdmat::CDMat;
+use crate::cmat::CMat;
+use crate::complex::Complex;
+#[cfg(feature = "alloc")]
+use crate::dmat::DMat;
+use crate::f64_abs;
+use crate::mat::Mat;
+use crate::smat::SMat;
+use core::fmt::{Display, Formatter, LowerExp, Write};
+
+//shared, allocation free formatting of matrices as used by the Display and LowerExp impls of Mat, DMat, SMat,
+//CMat and CDMat
+//column widths are measured by formatting every shown value once into a character counter
+
+const ROW_ELLIPSIS: &str = "⋮";
+const COLUMN_ELLIPSIS: &str = "…";
+const DIAGONAL_ELLIPSIS: &str = "⋱";
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Notation {
+    //e.g. 1250.5
+    Decimal,
+    //e.g. 1.2505e3
+    Scientific,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Alignment {
+    Left,
+    Center,
+    Right,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct DisplayOptions {
+    //digits after the decimal point, or as many as needed to represent each value exactly if None
+    pub precision: Option<usize>,
+    //minimum width of every column
+    pub min_width: usize,
+    pub notation: Notation,
+    pub alignment: Alignment,
+    //prefix positive values with '+'
+    pub sign_plus: bool,
+    //matrices with more rows or columns than this only show the first and last ones, eliding the rest
+    pub max_rows: usize,
+    pub max_cols: usize,
+}
+
+impl DisplayOptions {
+    //options used by Display for Mat and DMat, with the formatter's flags applied on top
+    pub const DEFAULT: Self = Self {
+        precision: None,
+        min_width: 0,
+        notation: Notation::Decimal,
+        alignment: Alignment::Center,
+        sign_plus: false,
+        max_rows: 20,
+        max_cols: 20,
+    };
+
+    //shows every row and column, however large the matrix
+    pub const UNLIMITED: Self = Self {
+        max_rows: usize::MAX,
+        max_cols: usize::MAX,
+        ..Self::DEFAULT
+    };
+
+    //overrides the options with the precision, width, alignment and sign flags set on the formatter, if any
+    #[must_use]
+    pub fn with_formatter(self, f: &Formatter<'_>) -> Self {
+        Self {
+            precision: f.precision().or(self.precision),
+            min_width: f.width().unwrap_or(self.min_width),
+            alignment: match f.align() {
+                Some(core::fmt::Alignment::Left) => Alignment::Left,
+                Some(core::fmt::Alignment::Center) => Alignment::Center,
+                Some(core::fmt::Alignment::Right) => Alignment::Right,
+                None => self.alignment,
+            },
+            sign_plus: f.sign_plus() || self.sign_plus,
+            ..self
+        }
+    }
+}
+
+impl Default for DisplayOptions {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+//counts the characters written to it
+struct CharCounter(usize);
+
+impl Write for CharCounter {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+pub(crate) fn write_value<W: Write>(
+    output: &mut W,
+    value: f64,
+    options: &DisplayOptions,
+) -> core::fmt::Result {
+    if options.sign_plus && value.is_sign_positive() && !value.is_nan() {
+        output.write_char('+')?;
+    }
+
+    match (options.notation, options.precision) {
+        (Notation::Decimal, None) => write!(output, "{value}"),
+        (Notation::Decimal, Some(precision)) => write!(output, "{value:.precision$}"),
+        (Notation::Scientific, None) => write!(output, "{value:e}"),
+        (Notation::Scientific, Some(precision)) => write!(output, "{value:.precision$e}"),
+    }
+}
+
+//matrix elements that can be written with DisplayOptions
+pub(crate) trait DisplayValue: Copy {
+    fn write_with<W: Write>(self, output: &mut W, options: &DisplayOptions) -> core::fmt::Result;
+}
+
+impl DisplayValue for f64 {
+    fn write_with<W: Write>(self, output: &mut W, options: &DisplayOptions) -> core::fmt::Result {
+        write_value(output, self, options)
+    }
+}
+
+//as the Display impl of Complex, with both parts formatted by the options and
+//the sign between them always written
+impl DisplayValue for Complex {
+    fn write_with<W: Write>(self, output: &mut W, options: &DisplayOptions) -> core::fmt::Result {
+        if self.im == 0.0 {
+            return write_value(output, self.re, options);
+        }
+
+        if self.re == 0.0 {
+            write_value(output, self.im, options)?;
+        } else {
+            write_value(output, self.re, options)?;
+            output.write_char(if self.im.is_sign_negative() { '-' } else { '+' })?;
+            let options = DisplayOptions {
+                sign_plus: false,
+                ..*options
+            };
+            write_value(output, f64_abs(self.im), &options)?;
+        }
+        output.write_char('i')
+    }
+}
+
+fn value_width<T: DisplayValue>(value: T, options: &DisplayOptions) -> usize {
+    let mut counter = CharCounter(0);
+    value
+        .write_with(&mut counter, options)
+        .expect("Counting characters cannot fail");
+    counter.0
+}
+
+//a shown row or column, either one of the matrix or the ellipsis standing in for the elided ones
+#[derive(Clone, Copy)]
+enum Slot {
+    Index(usize),
+    Elided,
+}
+
+//which rows or columns of a dimension of the given length are shown
+#[derive(Clone, Copy)]
+struct Shown {
+    len: usize,
+    head: usize,
+    tail: usize,
+}
+
+impl Shown {
+    fn new(len: usize, max: usize) -> Self {
+        if len <= max {
+            Self {
+                len,
+                head: len,
+                tail: 0,
+            }
+        } else {
+            Self {
+                len,
+                head: max.div_ceil(2),
+                tail: max / 2,
+            }
+        }
+    }
+
+    fn is_elided(self) -> bool {
+        self.head + self.tail < self.len
+    }
+
+    fn count(self) -> usize {
+        self.head + self.tail + usize::from(self.is_elided())
+    }
+
+    fn slots(self) -> impl Iterator<Item = Slot> {
+        let elided = self.is_elided().then_some(Slot::Elided);
+        (0..self.head)
+            .map(Slot::Index)
+            .chain(elided)
+            .chain((self.len - self.tail..self.len).map(Slot::Index))
+    }
+}
+
+fn pad<W: Write>(output: &mut W, count: usize) -> core::fmt::Result {
+    (0..count).try_for_each(|_| output.write_char(' '))
+}
+
+//writes the matrix as box-drawn rows of aligned columns, widths holding scratch space for at least
+//min(cols, max_cols + 1) column widths
+pub(crate) fn write_matrix<T: DisplayValue, F: Fn(usize, usize) -> T>(
+    f: &mut Formatter<'_>,
+    (rows, cols): (usize, usize),
+    value: F,
+    widths: &mut [usize],
+    options: &DisplayOptions,
+) -> core::fmt::Result {
+    let shown_rows = Shown::new(rows, options.max_rows);
+    let shown_cols = Shown::new(cols, options.max_cols);
+    if shown_rows.count() == 0 || shown_cols.count() == 0 {
+        return f.write_str("[ ]");
+    }
+
+    let widths = &mut widths[..shown_cols.count()];
+    for (width, col) in widths.iter_mut().zip(shown_cols.slots()) {
+        *width = match col {
+            Slot::Index(col) => shown_rows
+                .slots()
+                .map(|row| match row {
+                    Slot::Index(row) => value_width(value(row, col), options),
+                    Slot::Elided => 1,
+                })
+                .max()
+                .unwrap_or(0)
+                .max(options.min_width),
+            Slot::Elided => 1,
+        };
+    }
+
+    let row_count = shown_rows.count();
+    for (row_index, row) in shown_rows.slots().enumerate() {
+        let (start_char, end_char) = match row_index {
+            0 if row_count == 1 => ("[", "]"),
+            0 => ("┌", "┐\n"),
+            int if int == row_count - 1 => ("└", "┘"),
+            _ => ("│", "│\n"),
+        };
+        f.write_str(start_char)?;
+
+        for (&width, col) in widths.iter().zip(shown_cols.slots()) {
+            let length = match (row, col) {
+                (Slot::Index(row), Slot::Index(col)) => value_width(value(row, col), options),
+                _ => 1,
+            };
+            let (left, right) = match options.alignment {
+                Alignment::Left => (0, width - length),
+                //extra padding goes to the right, as with {:^}
+                Alignment::Center => ((width - length) / 2, width - length - (width - length) / 2),
+                Alignment::Right => (width - length, 0),
+            };
+
+            pad(f, left + 1)?;
+            match (row, col) {
+                (Slot::Index(row), Slot::Index(col)) => value(row, col).write_with(f, options)?,
+                (Slot::Index(_), Slot::Elided) => f.write_str(COLUMN_ELLIPSIS)?,
+                (Slot::Elided, Slot::Index(_)) => f.write_str(ROW_ELLIPSIS)?,
+                (Slot::Elided, Slot::Elided) => f.write_str(DIAGONAL_ELLIPSIS)?,
+            }
+            pad(f, right)?;
+        }
+
+        write!(f, " {end_char}")?;
+    }
+
+    Ok(())
+}
+
+//formats a matrix with the given options, returned by the display_with functions of Mat, DMat, SMat,
+//CMat and CDMat
+//flags set on the formatter take priority over the options
+pub struct DisplayWith<'a, M> {
+    mat: &'a M,
+    options: DisplayOptions,
+}
+
+//matrices written by write_matrix, each providing scratch space for its column widths
+pub(crate) trait WriteMatrix {
+    fn write(&self, f: &mut Formatter<'_>, options: &DisplayOptions) -> core::fmt::Result;
+}
+
+impl<const R: usize, const C: usize> WriteMatrix for Mat<R, C> {
+    fn write(&self, f: &mut Formatter<'_>, options: &DisplayOptions) -> core::fmt::Result {
+        write_matrix(f, (R, C), |row, col| self[row][col], &mut [0; C], options)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl WriteMatrix for DMat {
+    fn write(&self, f: &mut Formatter<'_>, options: &DisplayOptions) -> core::fmt::Result {
+        let mut widths = alloc::vec![0; self.cols().min(options.max_cols.saturating_add(1))];
+        write_matrix(
+            f,
+            (self.rows(), self.cols()),
+            |row, col| self[row][col],
+            &mut widths,
+            options,
+        )
+    }
+}
+
+impl<const CAP: usize> WriteMatrix for SMat<CAP> {
+    fn write(&self, f: &mut Formatter<'_>, options: &DisplayOptions) -> core::fmt::Result {
+        //there can be no more columns than values, so CAP widths are always enough
+        write_matrix(
+            f,
+            (self.rows(), self.cols()),
+            |row, col| self[row][col],
+            &mut [0; CAP],
+            options,
+        )
+    }
+}
+
+impl<const R: usize, const C: usize> WriteMatrix for CMat<R, C> {
+    fn write(&self, f: &mut Formatter<'_>, options: &DisplayOptions) -> core::fmt::Result {
+        write_matrix(f, (R, C), |row, col| self[row][col], &mut [0; C], options)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl WriteMatrix for CDMat {
+    fn write(&self, f: &mut Formatter<'_>, options: &DisplayOptions) -> core::fmt::Result {
+        let mut widths = alloc::vec![0; self.cols().min(options.max_cols.saturating_add(1))];
+        write_matrix(
+            f,
+            (self.rows(), self.cols()),
+            |row, col| self[row][col],
+            &mut widths,
+            options,
+        )
+    }
+}
+
+impl<M: WriteMatrix> Display for DisplayWith<'_, M> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.mat.write(f, &self.options.with_formatter(f))
+    }
+}
+
+const SCIENTIFIC: DisplayOptions = DisplayOptions {
+    notation: Notation::Scientific,
+    ..DisplayOptions::DEFAULT
+};
+
+//display_with, Display and LowerExp for each matrix type implementing WriteMatrix
+macro_rules! display_impls {
+    ($( $(#[$attr: meta])* [$($generics: tt)*] $ty: ty; )*) => {
+        $(
+            $(#[$attr])*
+            impl<$($generics)*> $ty {
+                #[must_use]
+                pub fn display_with(&self, options: DisplayOptions) -> DisplayWith<'_, Self> {
+                    DisplayWith { mat: self, options }
+                }
+            }
+
+            $(#[$attr])*
+            impl<$($generics)*> Display for $ty {
+                fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                    self.write(f, &DisplayOptions::DEFAULT.with_formatter(f))
+                }
+            }
+
+            $(#[$attr])*
+            impl<$($generics)*> LowerExp for $ty {
+                fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                    self.write(f, &SCIENTIFIC.with_formatter(f))
+                }
+            }
+        )*
+    };
+}
+
+display_impls! {
+    [const R: usize, const C: usize] Mat<R, C>;
+    #[cfg(feature = "alloc")] [] DMat;
+    [const CAP: usize] SMat<CAP>;
+    [const R: usize, const C: usize] CMat<R, C>;
+    #[cfg(feature = "alloc")] [] CDMat;
+}
+
+//output as source code or markup for other tools, returned by the display_latex, display_markdown,
+//display_matlab and display_numpy functions of Mat, DMat and SMat
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SourceFormat {
+    //\begin{bmatrix} 1 & 2 \\ 3 & 4 \end{bmatrix}
+    Latex,
+    //a table with a header of column indices
+    Markdown,
+    //[1 2; 3 4]
+    Matlab,
+    //np.array([[1.0, 2.0], [3.0, 4.0]])
+    Numpy,
+}
+
+//formats a matrix in a SourceFormat, honouring the formatter's precision and sign flags,
+//and writing values in scientific notation when formatted with {:e}
+pub struct DisplayAs<'a, M> {
+    mat: &'a M,
+    format: SourceFormat,
+}
+
+//replaces the 'e' of a value in scientific notation with a LaTeX power of ten
+struct LatexExponent<'a, W: Write> {
+    output: &'a mut W,
+    in_exponent: bool,
+}
+
+impl<W: Write> Write for LatexExponent<'_, W> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        for c in s.chars() {
+            if c == 'e' {
+                self.output.write_str(r" \times 10^{")?;
+                self.in_exponent = true;
+            } else {
+                self.output.write_char(c)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn write_source_value<W: Write>(
+    output: &mut W,
+    value: f64,
+    options: &DisplayOptions,
+    format: SourceFormat,
+) -> core::fmt::Result {
+    if !value.is_finite() {
+        if value.is_sign_negative() && !value.is_nan() {
+            output.write_char('-')?;
+        } else if options.sign_plus && !value.is_nan() {
+            output.write_char('+')?;
+        }
+
+        let name = match (format, value.is_nan()) {
+            (SourceFormat::Latex, true) => r"\text{NaN}",
+            (SourceFormat::Latex, false) => r"\infty",
+            (SourceFormat::Matlab | SourceFormat::Markdown, true) => "NaN",
+            (SourceFormat::Matlab | SourceFormat::Markdown, false) => "Inf",
+            (SourceFormat::Numpy, true) => "np.nan",
+            (SourceFormat::Numpy, false) => "np.inf",
+        };
+        return output.write_str(name);
+    }
+
+    match (format, options.notation, options.precision) {
+        (SourceFormat::Latex, Notation::Scientific, _) => {
+            let mut latex = LatexExponent {
+                output,
+                in_exponent: false,
+            };
+            write_value(&mut latex, value, options)?;
+            if latex.in_exponent {
+                output.write_char('}')?;
+            }
+            Ok(())
+        }
+        //python reads values without a decimal point or exponent as integers
+        (SourceFormat::Numpy, Notation::Decimal, None) => {
+            if options.sign_plus && value.is_sign_positive() {
+                output.write_char('+')?;
+            }
+            write!(output, "{value:?}")
+        }
+        (SourceFormat::Numpy, Notation::Decimal, Some(0)) => {
+            write_value(output, value, options)?;
+            output.write_char('.')
+        }
+        _ => write_value(output, value, options),
+    }
+}
+
+fn write_source<F: Fn(usize, usize) -> f64>(
+    f: &mut Formatter<'_>,
+    (rows, cols): (usize, usize),
+    value: F,
+    format: SourceFormat,
+    options: &DisplayOptions,
+) -> core::fmt::Result {
+    let (start, row_start, separator, row_end, row_separator, end) = match format {
+        SourceFormat::Latex => (
+            "\\begin{bmatrix}\n",
+            "",
+            " & ",
+            "",
+            " \\\\\n",
+            "\n\\end{bmatrix}",
+        ),
+        SourceFormat::Markdown => ("", "| ", " | ", " |", "\n", ""),
+        SourceFormat::Matlab => ("[", "", " ", "", "; ", "]"),
+        SourceFormat::Numpy => ("np.array([", "[", ", ", "]", ", ", "])"),
+    };
+
+    //literals of empty matrices cannot hold their dimensions
+    if rows == 0 || cols == 0 {
+        return match format {
+            SourceFormat::Latex => f.write_str("\\begin{bmatrix}\n\\end{bmatrix}"),
+            SourceFormat::Markdown => Ok(()),
+            SourceFormat::Matlab => write!(f, "zeros({rows}, {cols})"),
+            SourceFormat::Numpy => write!(f, "np.zeros(({rows}, {cols}))"),
+        };
+    }
+
+    f.write_str(start)?;
+
+    if format == SourceFormat::Markdown {
+        for col in 0..cols {
+            write!(f, "{}{col}", if col == 0 { row_start } else { separator })?;
+        }
+        f.write_str(row_end)?;
+        f.write_str(row_separator)?;
+        for _ in 0..cols {
+            f.write_str("|--:")?;
+        }
+        f.write_str("|")?;
+        f.write_str(row_separator)?;
+    }
+
+    for row in 0..rows {
+        if row > 0 {
+            f.write_str(row_separator)?;
+        }
+        f.write_str(row_start)?;
+        for col in 0..cols {
+            if col > 0 {
+                f.write_str(separator)?;
+            }
+            write_source_value(f, value(row, col), options, format)?;
+        }
+        f.write_str(row_end)?;
+    }
+
+    f.write_str(end)
+}
+
+//real matrices written by write_source
+pub(crate) trait WriteSource {
+    fn dimensions(&self) -> (usize, usize);
+    fn value(&self, row: usize, col: usize) -> f64;
+}
+
+impl<const R: usize, const C: usize> WriteSource for Mat<R, C> {
+    fn dimensions(&self) -> (usize, usize) {
+        (R, C)
+    }
+
+    fn value(&self, row: usize, col: usize) -> f64 {
+        self[row][col]
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl WriteSource for DMat {
+    fn dimensions(&self) -> (usize, usize) {
+        (self.rows(), self.cols())
+    }
+
+    fn value(&self, row: usize, col: usize) -> f64 {
+        self[row][col]
+    }
+}
+
+impl<const CAP: usize> WriteSource for SMat<CAP> {
+    fn dimensions(&self) -> (usize, usize) {
+        (self.rows(), self.cols())
+    }
+
+    fn value(&self, row: usize, col: usize) -> f64 {
+        self[row][col]
+    }
+}
+
+impl<M: WriteSource> Display for DisplayAs<'_, M> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let options = DisplayOptions::DEFAULT.with_formatter(f);
+        let value = |row, col| self.mat.value(row, col);
+        write_source(f, self.mat.dimensions(), value, self.format, &options)
+    }
+}
+
+impl<M: WriteSource> LowerExp for DisplayAs<'_, M> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let options = SCIENTIFIC.with_formatter(f);
+        let value = |row, col| self.mat.value(row, col);
+        write_source(f, self.mat.dimensions(), value, self.format, &options)
+    }
+}
+
+//display_latex, display_markdown, display_matlab and display_numpy for each matrix type implementing WriteSource
+macro_rules! source_display_impls {
+    ($( $(#[$attr: meta])* [$($generics: tt)*] $ty: ty; )*) => {
+        $(
+            $(#[$attr])*
+            impl<$($generics)*> $ty {
+                #[must_use]
+                pub fn display_latex(&self) -> DisplayAs<'_, Self> {
+                    DisplayAs {
+                        mat: self,
+                        format: SourceFormat::Latex,
+                    }
+                }
+
+                #[must_use]
+                pub fn display_markdown(&self) -> DisplayAs<'_, Self> {
+                    DisplayAs {
+                        mat: self,
+                        format: SourceFormat::Markdown,
+                    }
+                }
+
+                #[must_use]
+                pub fn display_matlab(&self) -> DisplayAs<'_, Self> {
+                    DisplayAs {
+                        mat: self,
+                        format: SourceFormat::Matlab,
+                    }
+                }
+
+                #[must_use]
+                pub fn display_numpy(&self) -> DisplayAs<'_, Self> {
+                    DisplayAs {
+                        mat: self,
+                        format: SourceFormat::Numpy,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+source_display_impls! {
+    [const R: usize, const C: usize] Mat<R, C>;
+    #[cfg(feature = "alloc")] [] DMat;
+    [const CAP: usize] SMat<CAP>;
+}
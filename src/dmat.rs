@@ -1,14 +1,60 @@
 extern crate alloc;
 use crate::f64_abs;
+use crate::gemm::axpy;
+use crate::parallel::for_each_chunk;
 use alloc::boxed::Box;
-use alloc::string::{String, ToString};
+use alloc::vec;
 use alloc::vec::Vec;
-use alloc::{format, vec};
 use core::fmt::Display;
 use core::ops::{
     Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
 };
 
+//rows up to this length are multiplied in place by *= without allocating,
+//longer ones are replaced by the ordinary product, unless multiplied with mul_assign_with
+const MUL_ASSIGN_STACK_SCRATCH: usize = 64;
+
+//error returned by the fallible constructors of DMat
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AllocError {
+    //rows * cols values would not fit in the address space
+    CapacityOverflow,
+    //the allocator could not provide the memory
+    AllocationFailed,
+}
+
+impl Display for AllocError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::CapacityOverflow => write!(f, "matrix size exceeds the maximum capacity"),
+            Self::AllocationFailed => write!(f, "memory allocation failed"),
+        }
+    }
+}
+
+impl core::error::Error for AllocError {}
+
+//number of values of a rows x cols matrix, panicking if it overflows
+//instead of wrapping around to a buffer that does not match the dimensions
+pub(crate) fn checked_len(rows: usize, cols: usize) -> usize {
+    rows.checked_mul(cols)
+        .expect("Attempted to create a matrix with more values than fit in memory")
+}
+
+//an empty vector with room for exactly rows * cols values
+fn try_with_capacity(rows: usize, cols: usize) -> Result<Vec<f64>, AllocError> {
+    //allocations are limited to isize::MAX bytes
+    let len = rows
+        .checked_mul(cols)
+        .filter(|&len| len <= isize::MAX as usize / core::mem::size_of::<f64>())
+        .ok_or(AllocError::CapacityOverflow)?;
+
+    let mut vec = Vec::new();
+    vec.try_reserve_exact(len)
+        .map_err(|_| AllocError::AllocationFailed)?;
+    Ok(vec)
+}
+
 #[derive(Debug, Clone)]
 pub struct DMat {
     vals: Box<[f64]>,
@@ -61,41 +107,35 @@ impl<'a> Iterator for RowIterator<'a> {
 
 impl DMat {
     #[must_use]
-    pub fn row_iter(&self) -> RowIterator {
+    pub fn row_iter(&self) -> RowIterator<'_> {
         RowIterator { mat: self, row: 0 }
     }
-}
 
-impl Display for DMat {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let max_column_lengths: Vec<usize> = self
-            .transpose()
-            .row_iter()
-            .map(|column| column.iter().map(|x| x.to_string().len()).max().unwrap())
-            .collect();
+    #[must_use]
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
 
-        for (row_index, row) in self.row_iter().enumerate() {
-            let row_string = row
-                .iter()
-                .enumerate()
-                .map(|(column_index, n)| {
-                    format!(
-                        "{:^len$}",
-                        n.to_string(),
-                        len = max_column_lengths[column_index]
-                    )
-                })
-                .collect::<Vec<String>>()
-                .join(" ");
-            let (start_char, end_char) = match row_index {
-                0 if self.rows == 1 => ("[", "]"),
-                0 => ("┌", "┐\n"),
-                int if int == self.rows - 1 => ("└", "┘"),
-                _ => ("│", "│\n"),
-            };
-            write!(f, "{start_char} {row_string} {end_char}")?;
-        }
-        Ok(())
+    #[must_use]
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    //values in row-major order
+    #[must_use]
+    pub fn as_slice(&self) -> &[f64] {
+        &self.vals
+    }
+
+    #[must_use]
+    pub fn as_mut_slice(&mut self) -> &mut [f64] {
+        &mut self.vals
+    }
+
+    //the values in row-major order, reusing the buffer
+    #[must_use]
+    pub fn into_vec(self) -> Vec<f64> {
+        self.vals.into_vec()
     }
 }
 
@@ -132,13 +172,21 @@ impl Add for &DMat {
     }
 }
 
+impl AddAssign<&Self> for DMat {
+    fn add_assign(&mut self, rhs: &Self) {
+        assert!(
+            (self.rows, self.cols) == (rhs.rows, rhs.cols),
+            "Attempted to add two matrices of different sizes"
+        );
+        for (val, rhs) in self.vals.iter_mut().zip(rhs.vals.iter()) {
+            *val += rhs;
+        }
+    }
+}
+
 impl AddAssign for DMat {
     fn add_assign(&mut self, rhs: Self) {
-        *self = Self {
-            vals: core::mem::take(&mut self.vals),
-            rows: self.rows,
-            cols: self.cols,
-        } + rhs;
+        *self += &rhs;
     }
 }
 
@@ -175,13 +223,21 @@ impl Sub for &DMat {
     }
 }
 
+impl SubAssign<&Self> for DMat {
+    fn sub_assign(&mut self, rhs: &Self) {
+        assert!(
+            (self.rows, self.cols) == (rhs.rows, rhs.cols),
+            "Attempted to subtract two matrices of different sizes"
+        );
+        for (val, rhs) in self.vals.iter_mut().zip(rhs.vals.iter()) {
+            *val -= rhs;
+        }
+    }
+}
+
 impl SubAssign for DMat {
     fn sub_assign(&mut self, rhs: Self) {
-        *self = Self {
-            vals: core::mem::take(&mut self.vals),
-            rows: self.rows,
-            cols: self.cols,
-        } - rhs;
+        *self -= &rhs;
     }
 }
 
@@ -234,26 +290,33 @@ impl Mul for &DMat {
             "Attempted to multiply two non-commutative matrices"
         );
 
-        let rhs_t = rhs.transpose();
+        let mut product = DMat::zero(self.rows, rhs.cols);
+        DMat::gemm(1.0, self, rhs, 0.0, &mut product);
+        product
+    }
+}
 
-        Self::Output::generate(self.rows, rhs.cols, |row, col| {
-            let row = &self[row];
-            let col = &rhs_t[col];
+impl MulAssign<&Self> for DMat {
+    fn mul_assign(&mut self, rhs: &Self) {
+        assert!(
+            self.cols == rhs.rows,
+            "Attempted to multiply two non-commutative matrices"
+        );
 
-            row.iter()
-                .enumerate()
-                .fold(0.0, |acc, (index, n)| acc + n * col[index])
-        })
+        //a non-square right hand side changes the number of columns, and rows longer than the stack
+        //scratch buffer would need a heap one, so both take the ordinary product into a new buffer
+        //mul_assign_with multiplies rows of any length in place with a scratch buffer kept by the caller
+        if rhs.rows != rhs.cols || self.cols > MUL_ASSIGN_STACK_SCRATCH {
+            *self = &*self * rhs;
+        } else {
+            self.mul_rows_in_place(rhs, &mut [0.0; MUL_ASSIGN_STACK_SCRATCH][..self.cols]);
+        }
     }
 }
 
 impl MulAssign for DMat {
     fn mul_assign(&mut self, rhs: Self) {
-        *self = Self {
-            vals: core::mem::take(&mut self.vals),
-            rows: self.rows,
-            cols: self.cols,
-        } * rhs;
+        *self *= &rhs;
     }
 }
 
@@ -317,15 +380,40 @@ impl DMat {
     #[must_use]
     pub fn zero(rows: usize, cols: usize) -> Self {
         Self {
-            vals: vec![0.0; rows * cols].into_boxed_slice(),
+            vals: vec![0.0; checked_len(rows, cols)].into_boxed_slice(),
+            rows,
+            cols,
+        }
+    }
+
+    //builds a matrix from values in row-major order
+    #[must_use]
+    pub fn from_vec(rows: usize, cols: usize, vals: Vec<f64>) -> Self {
+        assert!(
+            rows.checked_mul(cols) == Some(vals.len()),
+            "Attempted to build a matrix from the wrong number of values"
+        );
+
+        Self {
+            vals: vals.into_boxed_slice(),
             rows,
             cols,
         }
     }
 
+    #[cfg(not(feature = "parallel"))]
     #[must_use]
     pub fn generate<F: Fn(usize, usize) -> f64>(rows: usize, cols: usize, f: F) -> Self {
-        let vec: Vec<f64> = (0..rows * cols)
+        Self::generate_serial(rows, cols, f)
+    }
+
+    //generate on the calling thread, for closures that are not required to be Sync
+    pub(crate) fn generate_serial<F: Fn(usize, usize) -> f64>(
+        rows: usize,
+        cols: usize,
+        f: F,
+    ) -> Self {
+        let vec: Vec<f64> = (0..checked_len(rows, cols))
             .map(|index| f(index / cols, index % cols))
             .collect();
 
@@ -336,25 +424,212 @@ impl DMat {
         }
     }
 
+    //with the parallel feature, generate, map and mutate need Sync closures, as large matrices
+    //evaluate them on multiple threads
+    #[cfg(feature = "parallel")]
+    #[must_use]
+    pub fn generate<F: Fn(usize, usize) -> f64 + Sync>(rows: usize, cols: usize, f: F) -> Self {
+        let mut generated = Self::zero(rows, cols);
+        generated.mutate(|_, row, col| f(row, col));
+        generated
+    }
+
+    //fallible variants of the constructors and of clone, which return an error instead of
+    //aborting when the allocation fails or rows * cols overflows
+
+    /// Creates a `rows` x `cols` matrix of zeros.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AllocError` if `rows * cols` values exceed the maximum allocation size,
+    /// or if the allocator cannot provide them.
+    pub fn try_zero(rows: usize, cols: usize) -> Result<Self, AllocError> {
+        Self::try_generate(rows, cols, |_, _| 0.0)
+    }
+
+    /// Creates a `rows` x `cols` matrix with the value of `f(row, col)` at every position.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AllocError` if `rows * cols` values exceed the maximum allocation size,
+    /// or if the allocator cannot provide them.
+    pub fn try_generate<F: Fn(usize, usize) -> f64>(
+        rows: usize,
+        cols: usize,
+        f: F,
+    ) -> Result<Self, AllocError> {
+        let mut vec = try_with_capacity(rows, cols)?;
+        //fits in the reserved capacity, so extending cannot reallocate
+        vec.extend((0..rows * cols).map(|index| f(index / cols, index % cols)));
+
+        Ok(Self {
+            vals: vec.into_boxed_slice(),
+            rows,
+            cols,
+        })
+    }
+
+    /// Creates the `n` x `n` identity matrix.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AllocError` if `n * n` values exceed the maximum allocation size,
+    /// or if the allocator cannot provide them.
+    pub fn try_identity(n: usize) -> Result<Self, AllocError> {
+        Self::try_generate(n, n, |row, col| if row == col { 1.0 } else { 0.0 })
+    }
+
+    /// Clones the matrix.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AllocError` if the allocator cannot provide memory for the copy.
+    pub fn try_clone(&self) -> Result<Self, AllocError> {
+        let mut vec = try_with_capacity(self.rows, self.cols)?;
+        vec.extend_from_slice(&self.vals);
+
+        Ok(Self {
+            vals: vec.into_boxed_slice(),
+            rows: self.rows,
+            cols: self.cols,
+        })
+    }
+
+    #[cfg(not(feature = "parallel"))]
     pub fn mutate<F: Fn(f64, usize, usize) -> f64>(&mut self, f: F) {
         for (index, val) in self.vals.iter_mut().enumerate() {
             *val = f(*val, index / self.cols, index % self.cols);
         }
     }
 
+    #[cfg(feature = "parallel")]
+    pub fn mutate<F: Fn(f64, usize, usize) -> f64 + Sync>(&mut self, f: F) {
+        let len = self.vals.len();
+        for_each_chunk(&mut self.vals, self.cols, len, |row, values| {
+            for (col, val) in values.iter_mut().enumerate() {
+                *val = f(*val, row, col);
+            }
+        });
+    }
+
     #[must_use]
     pub fn identity(n: usize) -> Self {
         Self::generate(n, n, |row, col| if row == col { 1.0 } else { 0.0 })
     }
 
+    #[cfg(not(feature = "parallel"))]
     #[must_use]
     pub fn map<F: Fn(f64) -> f64>(&self, f: F) -> Self {
         Self::generate(self.rows, self.cols, |row, col| f(self[row][col]))
     }
 
+    #[cfg(feature = "parallel")]
+    #[must_use]
+    pub fn map<F: Fn(f64) -> f64 + Sync>(&self, f: F) -> Self {
+        let mut mapped = self.clone();
+        mapped.mutate(|val, _, _| f(val));
+        mapped
+    }
+
     #[must_use]
     pub fn transpose(&self) -> Self {
-        Self::generate(self.cols, self.rows, |row, col| self[col][row])
+        let mut transpose = Self::zero(self.cols, self.rows);
+        Self::transpose_into(self, &mut transpose);
+        transpose
+    }
+
+    //variants of the operators writing into an existing matrix, which is only reallocated
+    //if it does not already hold the number of values of the result
+
+    fn reshape(&mut self, rows: usize, cols: usize) {
+        let len = checked_len(rows, cols);
+        if self.vals.len() != len {
+            self.vals = vec![0.0; len].into_boxed_slice();
+        }
+        self.rows = rows;
+        self.cols = cols;
+    }
+
+    pub fn add_into(a: &DMat, b: &DMat, out: &mut DMat) {
+        assert!(
+            (a.rows, a.cols) == (b.rows, b.cols),
+            "Attempted to add two matrices of different sizes"
+        );
+        out.reshape(a.rows, a.cols);
+        for ((out, a), b) in out.vals.iter_mut().zip(a.vals.iter()).zip(b.vals.iter()) {
+            *out = a + b;
+        }
+    }
+
+    pub fn sub_into(a: &DMat, b: &DMat, out: &mut DMat) {
+        assert!(
+            (a.rows, a.cols) == (b.rows, b.cols),
+            "Attempted to subtract two matrices of different sizes"
+        );
+        out.reshape(a.rows, a.cols);
+        for ((out, a), b) in out.vals.iter_mut().zip(a.vals.iter()).zip(b.vals.iter()) {
+            *out = a - b;
+        }
+    }
+
+    pub fn mul_into(a: &DMat, b: &DMat, out: &mut DMat) {
+        assert!(
+            a.cols == b.rows,
+            "Attempted to multiply two non-commutative matrices"
+        );
+        out.reshape(a.rows, b.cols);
+        Self::gemm(1.0, a, b, 0.0, out);
+    }
+
+    pub fn scale_into<T: Into<f64>>(a: &DMat, scalar: T, out: &mut DMat) {
+        let scalar: f64 = scalar.into();
+        out.reshape(a.rows, a.cols);
+        for (out, a) in out.vals.iter_mut().zip(a.vals.iter()) {
+            *out = a * scalar;
+        }
+    }
+
+    //*= with a scratch buffer kept by the caller, which grows to the number of columns on first use,
+    //so that rows of any length are multiplied in place without allocating after that
+    //a non-square rhs changes the number of columns, and always reallocates the matrix
+    pub fn mul_assign_with(&mut self, rhs: &DMat, scratch: &mut Vec<f64>) {
+        assert!(
+            self.cols == rhs.rows,
+            "Attempted to multiply two non-commutative matrices"
+        );
+
+        if rhs.rows != rhs.cols {
+            *self = &*self * rhs;
+            return;
+        }
+
+        if scratch.len() < self.cols {
+            scratch.resize(self.cols, 0.0);
+        }
+        self.mul_rows_in_place(rhs, &mut scratch[..self.cols]);
+    }
+
+    //replaces each row by its product with the square rhs, accumulated in the same order as gemm,
+    //the original row being copied to the scratch buffer
+    fn mul_rows_in_place(&mut self, rhs: &DMat, scratch: &mut [f64]) {
+        for row in 0..self.rows {
+            scratch.copy_from_slice(&self[row]);
+            let row = &mut self[row];
+            row.fill(0.0);
+            for (index, &n) in scratch.iter().enumerate() {
+                axpy(n, &rhs[index], row);
+            }
+        }
+    }
+
+    pub fn transpose_into(a: &DMat, out: &mut DMat) {
+        out.reshape(a.cols, a.rows);
+        //each row of the output is a column of a
+        for_each_chunk(&mut out.vals, a.rows, a.vals.len(), |col, out_row| {
+            for (row, n) in out_row.iter_mut().enumerate() {
+                *n = a.vals[row * a.cols + col];
+            }
+        });
     }
 
     #[must_use]
@@ -417,23 +692,19 @@ impl DMat {
         let mut transformation_coefficient = 1.0;
 
         for k in 0..size {
-            //find k-th pivot
-            //TODO: replace this hideous transpose computation once a proper column access method is implemented
-            let pivot = self.transpose()[k]
-                .iter()
-                .enumerate()
-                .fold(k, |acc, (index, x)| {
-                    if f64_abs(*x) > f64_abs(self[acc][k]) {
-                        index
-                    } else {
-                        k
-                    }
-                });
+            //find k-th pivot, the largest element by magnitude on or below the diagonal
+            let pivot = (k..size).fold(k, |acc, index| {
+                if f64_abs(self[index][k]) > f64_abs(self[acc][k]) {
+                    index
+                } else {
+                    acc
+                }
+            });
 
             if self[pivot][k] == 0.0 {
                 //matrix is singular
                 return 0.0;
-            };
+            }
 
             //swap rows, flip transformation coefficient
             if k != pivot {
@@ -446,16 +717,18 @@ impl DMat {
                 transformation_coefficient *= -1.0;
             }
 
-            //for all rows below pivot
-            for i in k + 1..size {
-                let c = -self[i][k] / self[k][k];
+            //for all rows below pivot, on multiple threads with the parallel feature
+            let (upper, lower) = self.vals.split_at_mut((k + 1) * size);
+            let pivot_row = &upper[k * size..];
+            for_each_chunk(lower, size, lower.len(), |_, row| {
+                let c = -row[k] / pivot_row[k];
                 //for all remaining elements in current row
-                for j in k + 1..size {
-                    self[i][j] += self[k][j] * c;
+                for (n, pivot) in row[k + 1..].iter_mut().zip(&pivot_row[k + 1..]) {
+                    *n += pivot * c;
                 }
                 //fill lower triangle with 0s
-                self[i][k] = 0.0;
-            }
+                row[k] = 0.0;
+            });
         }
 
         //return product of elements in diagonal multiplied by the transformation coefficient
@@ -472,14 +745,78 @@ impl DMat {
         self.clone().to_determinant()
     }
 
+    //returns None if the matrix is singular, which is only detected by a pivot of exactly zero:
+    //nearly singular matrices give inverses dominated by rounding errors
     #[must_use]
-    pub fn to_inverse(/*mut self*/) -> DMat {
-        todo!();
+    pub fn to_inverse(mut self) -> Option<DMat> {
+        //inverse is undefined for non-square matrices
+        assert!(
+            self.rows == self.cols,
+            "Attempted to take inverse of non-square matrix"
+        );
+
+        //gauss-jordan elimination with partial pivoting on [self | identity]
+        let size = self.rows;
+        let mut inverse = Self::identity(size);
+        let (mut pivot_row, mut inverse_pivot_row, mut factors) =
+            (vec![0.0; size], vec![0.0; size], vec![0.0; size]);
+
+        for k in 0..size {
+            let pivot = (k..size).fold(k, |acc, index| {
+                if f64_abs(self[index][k]) > f64_abs(self[acc][k]) {
+                    index
+                } else {
+                    acc
+                }
+            });
+
+            if self[pivot][k] == 0.0 {
+                //matrix is singular
+                return None;
+            }
+
+            self.swap_rows(k, pivot);
+            inverse.swap_rows(k, pivot);
+
+            //divide current row by pivot element
+            let divisor = self[k][k];
+            for col in 0..size {
+                self[k][col] /= divisor;
+                inverse[k][col] /= divisor;
+            }
+
+            //eliminate the current column from every other row, on multiple threads with the parallel
+            //feature, reading the pivot rows and factors from copies as every row is updated in place
+            pivot_row.copy_from_slice(&self[k]);
+            inverse_pivot_row.copy_from_slice(&inverse[k]);
+            for (factor, row) in factors.iter_mut().zip(self.row_iter()) {
+                *factor = row[k];
+            }
+            for (mat, pivot_row) in [(&mut self, &pivot_row), (&mut inverse, &inverse_pivot_row)] {
+                for_each_chunk(&mut mat.vals, size, size * size, |row, values| {
+                    if row != k {
+                        for (value, pivot) in values.iter_mut().zip(pivot_row) {
+                            *value -= pivot * factors[row];
+                        }
+                    }
+                });
+            }
+        }
+
+        Some(inverse)
     }
 
     #[must_use]
-    pub fn inverse(&self) -> DMat {
-        todo!();
+    pub fn inverse(&self) -> Option<DMat> {
+        self.clone().to_inverse()
+    }
+
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        if a != b {
+            for col in 0..self.cols {
+                self.vals.swap(a * self.cols + col, b * self.cols + col);
+            }
+        }
     }
 }
 
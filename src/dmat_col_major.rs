@@ -0,0 +1,290 @@
+extern crate alloc;
+use crate::display::{write_matrix, DisplayOptions};
+use crate::dmat::{checked_len, DMat};
+use crate::f64_abs;
+use crate::gemm::gemm_slices;
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::Display;
+use core::ops::{Add, Index, IndexMut, Mul, Sub};
+
+//dynamically sized matrix stored in column-major order, as used by Fortran, BLAS and LAPACK
+//
+//a row-major matrix and the column-major storage of its transpose hold the same values in the same order,
+//so into_col_major_transpose and into_row_major_transpose convert between DMat and DMatColMajor
+//by reusing the buffer, without copying
+#[derive(Debug, Clone, PartialEq)]
+pub struct DMatColMajor {
+    vals: Box<[f64]>,
+    rows: usize,
+    cols: usize,
+}
+
+//indexing returns a column, so that mat[col][row] is the value at (row, col)
+impl Index<usize> for DMatColMajor {
+    type Output = [f64];
+    fn index(&self, index: usize) -> &Self::Output {
+        let starting_idx = self.rows * index;
+        &self.vals[starting_idx..(starting_idx + self.rows)]
+    }
+}
+
+impl IndexMut<usize> for DMatColMajor {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let starting_idx = self.rows * index;
+        &mut self.vals[starting_idx..(starting_idx + self.rows)]
+    }
+}
+
+impl Display for DMatColMajor {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let options = DisplayOptions::DEFAULT.with_formatter(f);
+        let mut widths = vec![0; self.cols.min(options.max_cols.saturating_add(1))];
+        write_matrix(
+            f,
+            (self.rows, self.cols),
+            |row, col| self[col][row],
+            &mut widths,
+            &options,
+        )
+    }
+}
+
+impl Add for &DMatColMajor {
+    type Output = DMatColMajor;
+    fn add(self, rhs: Self) -> Self::Output {
+        assert!(
+            (self.rows, self.cols) == (rhs.rows, rhs.cols),
+            "Attempted to add two matrices of different sizes"
+        );
+        let vals = self.vals.iter().zip(rhs.vals.iter()).map(|(a, b)| a + b);
+        DMatColMajor::from_vec(self.rows, self.cols, vals.collect())
+    }
+}
+
+impl Sub for &DMatColMajor {
+    type Output = DMatColMajor;
+    fn sub(self, rhs: Self) -> Self::Output {
+        assert!(
+            (self.rows, self.cols) == (rhs.rows, rhs.cols),
+            "Attempted to subtract two matrices of different sizes"
+        );
+        let vals = self.vals.iter().zip(rhs.vals.iter()).map(|(a, b)| a - b);
+        DMatColMajor::from_vec(self.rows, self.cols, vals.collect())
+    }
+}
+
+impl<T: Into<f64>> Mul<T> for &DMatColMajor {
+    type Output = DMatColMajor;
+    fn mul(self, scalar: T) -> Self::Output {
+        let scalar: f64 = scalar.into();
+        self.map(|n| n * scalar)
+    }
+}
+
+impl Mul for &DMatColMajor {
+    type Output = DMatColMajor;
+    fn mul(self, rhs: Self) -> Self::Output {
+        assert!(
+            self.cols == rhs.rows,
+            "Attempted to multiply two non-commutative matrices"
+        );
+
+        //the column-major storage of self * rhs is the row-major storage of rhs^T * self^T,
+        //whose operands are the row-major views of the buffers of rhs and self
+        let mut product = DMatColMajor::zero(self.rows, rhs.cols);
+        gemm_slices(
+            1.0,
+            &rhs.vals,
+            &self.vals,
+            (rhs.cols, rhs.rows, self.rows),
+            0.0,
+            &mut product.vals,
+        );
+        product
+    }
+}
+
+impl From<&DMat> for DMatColMajor {
+    fn from(value: &DMat) -> Self {
+        Self::generate(value.rows(), value.cols(), |row, col| value[row][col])
+    }
+}
+
+impl From<&DMatColMajor> for DMat {
+    fn from(value: &DMatColMajor) -> Self {
+        DMat::generate(value.rows, value.cols, |row, col| value[col][row])
+    }
+}
+
+impl DMatColMajor {
+    #[must_use]
+    pub fn zero(rows: usize, cols: usize) -> Self {
+        Self {
+            vals: vec![0.0; checked_len(rows, cols)].into_boxed_slice(),
+            rows,
+            cols,
+        }
+    }
+
+    //builds a matrix from values in column-major order
+    #[must_use]
+    pub fn from_vec(rows: usize, cols: usize, vals: Vec<f64>) -> Self {
+        assert!(
+            rows.checked_mul(cols) == Some(vals.len()),
+            "Attempted to build a matrix from the wrong number of values"
+        );
+
+        Self {
+            vals: vals.into_boxed_slice(),
+            rows,
+            cols,
+        }
+    }
+
+    #[must_use]
+    pub fn generate<F: Fn(usize, usize) -> f64>(rows: usize, cols: usize, f: F) -> Self {
+        let vec: Vec<f64> = (0..checked_len(rows, cols))
+            .map(|index| f(index % rows, index / rows))
+            .collect();
+
+        Self {
+            vals: vec.into_boxed_slice(),
+            rows,
+            cols,
+        }
+    }
+
+    #[must_use]
+    pub fn identity(n: usize) -> Self {
+        Self::generate(n, n, |row, col| if row == col { 1.0 } else { 0.0 })
+    }
+
+    #[must_use]
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    #[must_use]
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    //values in column-major order
+    #[must_use]
+    pub fn as_slice(&self) -> &[f64] {
+        &self.vals
+    }
+
+    #[must_use]
+    pub fn as_mut_slice(&mut self) -> &mut [f64] {
+        &mut self.vals
+    }
+
+    pub fn col_iter(&self) -> impl Iterator<Item = &[f64]> {
+        (0..self.cols).map(|col| &self[col])
+    }
+
+    #[must_use]
+    pub fn map<F: Fn(f64) -> f64>(&self, f: F) -> Self {
+        Self::from_vec(
+            self.rows,
+            self.cols,
+            self.vals.iter().map(|&n| f(n)).collect(),
+        )
+    }
+
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        Self::generate(self.cols, self.rows, |row, col| self[row][col])
+    }
+
+    //the same matrix in row-major order, copying the values
+    #[must_use]
+    pub fn to_row_major(&self) -> DMat {
+        DMat::from(self)
+    }
+
+    //the transpose of the matrix in row-major order, reusing the buffer
+    #[must_use]
+    pub fn into_row_major_transpose(self) -> DMat {
+        DMat::from_vec(self.cols, self.rows, self.vals.into_vec())
+    }
+
+    #[must_use]
+    pub fn to_determinant(mut self) -> f64 {
+        //determinant is undefined for non-square matrices
+        assert!(
+            self.rows == self.cols,
+            "Attempted to take determinant of non-square matrix"
+        );
+
+        let size = self.rows;
+        let mut transformation_coefficient = 1.0;
+
+        //gaussian elimination with partial pivoting, traversing contiguous columns
+        for k in 0..size {
+            //find k-th pivot, the largest element by magnitude on or below the diagonal of column k
+            let pivot = (k..size).fold(k, |acc, index| {
+                if f64_abs(self[k][index]) > f64_abs(self[k][acc]) {
+                    index
+                } else {
+                    acc
+                }
+            });
+
+            if self[k][pivot] == 0.0 {
+                //matrix is singular
+                return 0.0;
+            }
+
+            //swap rows, flip transformation coefficient
+            if k != pivot {
+                for col in k..size {
+                    self[col].swap(k, pivot);
+                }
+                transformation_coefficient *= -1.0;
+            }
+
+            //store the elimination factors below the pivot, then subtract the scaled pivot column
+            //from the lower part of every remaining column
+            let (left, right) = self.vals.split_at_mut((k + 1) * size);
+            let pivot_col = &mut left[k * size..];
+            let pivot_value = pivot_col[k];
+            for n in &mut pivot_col[k + 1..] {
+                *n /= pivot_value;
+            }
+
+            for col in right.chunks_exact_mut(size) {
+                let c = col[k];
+                for (n, factor) in col[k + 1..].iter_mut().zip(&pivot_col[k + 1..]) {
+                    *n -= factor * c;
+                }
+            }
+        }
+
+        let diagonal_product = (0..size).fold(1.0, |acc, index| acc * self[index][index]);
+        diagonal_product * transformation_coefficient
+    }
+
+    #[must_use]
+    pub fn determinant(&self) -> f64 {
+        self.clone().to_determinant()
+    }
+}
+
+impl DMat {
+    //the same matrix in column-major order, copying the values
+    #[must_use]
+    pub fn to_col_major(&self) -> DMatColMajor {
+        DMatColMajor::from(self)
+    }
+
+    //the transpose of the matrix in column-major order, reusing the buffer
+    #[must_use]
+    pub fn into_col_major_transpose(self) -> DMatColMajor {
+        let (rows, cols) = (self.rows(), self.cols());
+        DMatColMajor::from_vec(cols, rows, self.into_vec())
+    }
+}
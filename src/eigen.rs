@@ -0,0 +1,133 @@
+extern crate alloc;
+use crate::dmat::DMat;
+use crate::f64_abs;
+use alloc::vec::Vec;
+
+const JACOBI_MAX_SWEEPS: usize = 100;
+const JACOBI_TOLERANCE: f64 = 1e-14;
+
+//eigendecomposition of a symmetric matrix, self = vectors * diag(values) * vectors^T
+//values are sorted in decreasing order, and column i of vectors is the unit eigenvector of values[i],
+//with the sign making its largest component (by magnitude) positive
+#[derive(Debug, PartialEq, Clone)]
+pub struct SymmetricEigen {
+    pub values: Vec<f64>,
+    pub vectors: DMat,
+}
+
+impl SymmetricEigen {
+    #[must_use]
+    pub fn to_matrix(&self) -> DMat {
+        let scaled = DMat::generate(self.vectors.rows(), self.vectors.cols(), |row, col| {
+            self.vectors[row][col] * self.values[col]
+        });
+        scaled * self.vectors.transpose()
+    }
+}
+
+//replaces columns p and q of the matrix by their rotation by the angle with the given cosine and sine
+fn rotate_cols(mat: &mut DMat, p: usize, q: usize, (cos, sin): (f64, f64)) {
+    for row in 0..mat.rows() {
+        let (first, second) = (mat[row][p], mat[row][q]);
+        mat[row][p] = cos * first - sin * second;
+        mat[row][q] = sin * first + cos * second;
+    }
+}
+
+fn rotate_rows(mat: &mut DMat, p: usize, q: usize, (cos, sin): (f64, f64)) {
+    for col in 0..mat.cols() {
+        let (first, second) = (mat[p][col], mat[q][col]);
+        mat[p][col] = cos * first - sin * second;
+        mat[q][col] = sin * first + cos * second;
+    }
+}
+
+impl DMat {
+    //eigenvalues and eigenvectors of a symmetric matrix, using the cyclic Jacobi method
+    //the matrix is symmetrized first, so only its symmetric part is decomposed
+    //returns None if the iteration does not converge, as for matrices with NaN values
+    #[must_use]
+    pub fn symmetric_eigen(&self) -> Option<SymmetricEigen> {
+        assert!(
+            self.rows() == self.cols(),
+            "Attempted to take eigendecomposition of non-square matrix"
+        );
+
+        let size = self.rows();
+        let mut diagonalized = (self + &self.transpose()) * 0.5;
+        let mut vectors = DMat::identity(size);
+
+        //rotations preserve the frobenius norm, so the off-diagonal part is compared against it
+        let norm_squared = diagonalized
+            .as_slice()
+            .iter()
+            .fold(0.0, |acc, n| acc + n * n);
+        let tolerance = JACOBI_TOLERANCE * JACOBI_TOLERANCE * norm_squared;
+
+        let mut converged = false;
+        for _ in 0..JACOBI_MAX_SWEEPS {
+            let off_diagonal_squared = (0..size).fold(0.0, |acc, row| {
+                diagonalized[row][row + 1..]
+                    .iter()
+                    .fold(acc, |acc, n| acc + 2.0 * n * n)
+            });
+            if off_diagonal_squared <= tolerance {
+                converged = true;
+                break;
+            }
+
+            //a rotation in the (p, q) plane zeroing the (p, q) element, for every pair in turn
+            for p in 0..size {
+                for q in p + 1..size {
+                    let apq = diagonalized[p][q];
+                    if apq == 0.0 {
+                        continue;
+                    }
+
+                    //the smaller root of t^2 + 2 theta t - 1 = 0, t being the tangent of the angle
+                    let theta = (diagonalized[q][q] - diagonalized[p][p]) / (2.0 * apq);
+                    let sign = if theta >= 0.0 { 1.0 } else { -1.0 };
+                    let t = sign / (f64_abs(theta) + libm::sqrt(theta * theta + 1.0));
+                    let c = 1.0 / libm::sqrt(t * t + 1.0);
+                    let s = t * c;
+
+                    rotate_cols(&mut diagonalized, p, q, (c, s));
+                    rotate_rows(&mut diagonalized, p, q, (c, s));
+                    rotate_cols(&mut vectors, p, q, (c, s));
+                    //zeroed by the rotation, up to rounding
+                    diagonalized[p][q] = 0.0;
+                    diagonalized[q][p] = 0.0;
+                }
+            }
+        }
+
+        if !converged {
+            return None;
+        }
+
+        let mut order: Vec<usize> = (0..size).collect();
+        order.sort_by(|&a, &b| diagonalized[b][b].total_cmp(&diagonalized[a][a]));
+
+        let values = order
+            .iter()
+            .map(|&index| diagonalized[index][index])
+            .collect();
+        let mut vectors = DMat::generate(size, size, |row, col| vectors[row][order[col]]);
+        for col in 0..size {
+            let largest = (0..size).fold(0, |acc, row| {
+                if f64_abs(vectors[row][col]) > f64_abs(vectors[acc][col]) {
+                    row
+                } else {
+                    acc
+                }
+            });
+            if vectors[largest][col] < 0.0 {
+                for row in 0..size {
+                    vectors[row][col] = -vectors[row][col];
+                }
+            }
+        }
+
+        Some(SymmetricEigen { values, vectors })
+    }
+}
@@ -0,0 +1,120 @@
+#[cfg(feature = "alloc")]
+use crate::dmat::DMat;
+use crate::mat::Mat;
+
+//elementwise operations between matrices, with NumPy-style broadcasting
+//
+//for DMat, two shapes are compatible when, in each dimension, they are equal or one of them is 1,
+//the dimension of size 1 being repeated to match the other: a 1 x C row is applied to every row,
+//an R x 1 column to every column and a 1 x 1 matrix to every value
+//for Mat, shapes are checked at compile time, with separate functions for rows and columns
+
+impl<const R: usize, const C: usize> Mat<R, C> {
+    #[must_use]
+    pub fn zip_map<F: Fn(f64, f64) -> f64>(&self, other: &Self, f: F) -> Self {
+        Self::generate(|row, col| f(self[row][col], other[row][col]))
+    }
+
+    //applies f between every row and the given row
+    #[must_use]
+    pub fn zip_map_row<F: Fn(f64, f64) -> f64>(&self, other: &Mat<1, C>, f: F) -> Self {
+        Self::generate(|row, col| f(self[row][col], other[0][col]))
+    }
+
+    //applies f between every column and the given column
+    #[must_use]
+    pub fn zip_map_col<F: Fn(f64, f64) -> f64>(&self, other: &Mat<R, 1>, f: F) -> Self {
+        Self::generate(|row, col| f(self[row][col], other[row][0]))
+    }
+
+    #[must_use]
+    pub fn hadamard(&self, other: &Self) -> Self {
+        self.zip_map(other, |a, b| a * b)
+    }
+
+    #[must_use]
+    pub fn elementwise_div(&self, other: &Self) -> Self {
+        self.zip_map(other, |a, b| a / b)
+    }
+
+    //the matrix with the given row repeated R times
+    #[must_use]
+    pub fn broadcast_row(row: &Mat<1, C>) -> Self {
+        Self::generate(|_, col| row[0][col])
+    }
+
+    //the matrix with the given column repeated C times
+    #[must_use]
+    pub fn broadcast_col(col: &Mat<R, 1>) -> Self {
+        Self::generate(|row, _| col[row][0])
+    }
+}
+
+//the shape of the result of broadcasting two shapes, if they are compatible
+#[cfg(feature = "alloc")]
+fn broadcast_shape(a: (usize, usize), b: (usize, usize)) -> Option<(usize, usize)> {
+    let dimension = |a: usize, b: usize| match (a, b) {
+        _ if a == b => Some(a),
+        (1, _) => Some(b),
+        (_, 1) => Some(a),
+        _ => None,
+    };
+    Some((dimension(a.0, b.0)?, dimension(a.1, b.1)?))
+}
+
+//the value at (row, col) of the matrix once broadcast, indices into a dimension of size 1
+//always selecting its only element
+#[cfg(feature = "alloc")]
+fn broadcast_value(mat: &DMat, row: usize, col: usize) -> f64 {
+    mat[row.min(mat.rows() - 1)][col.min(mat.cols() - 1)]
+}
+
+#[cfg(feature = "alloc")]
+impl DMat {
+    //applies f to every pair of values, broadcasting the two matrices to a common shape
+    #[must_use]
+    pub fn zip_map<F: Fn(f64, f64) -> f64>(&self, other: &DMat, f: F) -> DMat {
+        let (rows, cols) =
+            broadcast_shape((self.rows(), self.cols()), (other.rows(), other.cols()))
+                .expect("Attempted to broadcast matrices of incompatible sizes");
+
+        DMat::generate_serial(rows, cols, |row, col| {
+            f(
+                broadcast_value(self, row, col),
+                broadcast_value(other, row, col),
+            )
+        })
+    }
+
+    #[must_use]
+    pub fn hadamard(&self, other: &DMat) -> DMat {
+        self.zip_map(other, |a, b| a * b)
+    }
+
+    #[must_use]
+    pub fn elementwise_div(&self, other: &DMat) -> DMat {
+        self.zip_map(other, |a, b| a / b)
+    }
+
+    //unlike the + and - operators, which require equal sizes, these broadcast the two matrices
+
+    #[must_use]
+    pub fn broadcast_add(&self, other: &DMat) -> DMat {
+        self.zip_map(other, |a, b| a + b)
+    }
+
+    #[must_use]
+    pub fn broadcast_sub(&self, other: &DMat) -> DMat {
+        self.zip_map(other, |a, b| a - b)
+    }
+
+    //the matrix repeated along its dimensions of size 1 to the given shape
+    #[must_use]
+    pub fn broadcast_to(&self, rows: usize, cols: usize) -> DMat {
+        assert!(
+            broadcast_shape((self.rows(), self.cols()), (rows, cols)) == Some((rows, cols)),
+            "Attempted to broadcast a matrix to an incompatible size"
+        );
+        DMat::generate(rows, cols, |row, col| broadcast_value(self, row, col))
+    }
+}
@@ -0,0 +1,141 @@
+#[cfg(feature = "alloc")]
+use crate::dmat::DMat;
+use crate::parallel::for_each_chunk;
+
+//cache-blocked general matrix multiplication, C = alpha * A * B + beta * C
+//
+//the product is computed in i-k-j order, so that the innermost loop adds a scaled, contiguous row
+//segment of B onto a contiguous row segment of C, which vectorizes well
+//the k and j loops are tiled so that the KC x NC block of B being read stays in cache while a band of
+//rows of A and C streams past it
+//
+//blocks are read in place rather than packed into contiguous panels as BLAS kernels do: each row
+//segment of a block of B is already contiguous, and packing would need a scratch buffer, which
+//would either allocate (DMat::mul_into and SMat::gemm never do) or take KC * NC values of stack
+//
+//every entry of C accumulates its products in increasing k, whichever kernel is used, so results are
+//bitwise identical between the scalar and SIMD kernels and to a naive dot product loop
+
+//rows of B per block
+const KC: usize = 128;
+//columns of B and C per block
+const NC: usize = 256;
+//rows of A and C per band, the unit of work handed to each thread
+const ROWS_PER_BAND: usize = 32;
+
+//y += alpha * x
+#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+pub(crate) fn axpy(alpha: f64, x: &[f64], y: &mut [f64]) {
+    for (y, x) in y.iter_mut().zip(x) {
+        *y += alpha * x;
+    }
+}
+
+//y += alpha * x, 4 lanes at a time with AVX when the target enables it, 2 at a time with SSE2 otherwise
+//multiplications and additions stay separate (no fused multiply-add) to round exactly like the scalar kernel
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+pub(crate) fn axpy(alpha: f64, x: &[f64], y: &mut [f64]) {
+    #[cfg(target_feature = "avx")]
+    use core::arch::x86_64::{
+        _mm256_add_pd as add, _mm256_loadu_pd as load, _mm256_mul_pd as mul,
+        _mm256_set1_pd as splat, _mm256_storeu_pd as store,
+    };
+    #[cfg(not(target_feature = "avx"))]
+    use core::arch::x86_64::{
+        _mm_add_pd as add, _mm_loadu_pd as load, _mm_mul_pd as mul, _mm_set1_pd as splat,
+        _mm_storeu_pd as store,
+    };
+    const LANES: usize = if cfg!(target_feature = "avx") { 4 } else { 2 };
+
+    let len = x.len().min(y.len());
+    let chunks = len / LANES * LANES;
+
+    //SAFETY: SSE2 is part of the x86_64 baseline and AVX is only used when enabled at compile time,
+    //every load and store is unaligned and stays within the first `chunks` <= len elements of x and y
+    unsafe {
+        let alpha_lanes = splat(alpha);
+        for index in (0..chunks).step_by(LANES) {
+            let x_lanes = load(x.as_ptr().add(index));
+            let y_lanes = load(y.as_ptr().add(index));
+            store(
+                y.as_mut_ptr().add(index),
+                add(y_lanes, mul(alpha_lanes, x_lanes)),
+            );
+        }
+    }
+
+    for (y, x) in y[chunks..len].iter_mut().zip(&x[chunks..len]) {
+        *y += alpha * x;
+    }
+}
+
+//C = alpha * A * B + beta * C on row-major slices, A being rows x inner, B inner x cols and C rows x cols
+pub(crate) fn gemm_slices(
+    alpha: f64,
+    a: &[f64],
+    b: &[f64],
+    (rows, inner, cols): (usize, usize, usize),
+    beta: f64,
+    c: &mut [f64],
+) {
+    #[allow(clippy::float_cmp)]
+    if beta == 0.0 {
+        c.fill(0.0);
+    } else if beta != 1.0 {
+        for n in c.iter_mut() {
+            *n *= beta;
+        }
+    }
+
+    #[allow(clippy::float_cmp)]
+    if alpha == 0.0 || inner == 0 {
+        return;
+    }
+
+    //rows of C are split into fixed-size bands, processed on multiple threads with the parallel feature
+    for_each_chunk(c, ROWS_PER_BAND * cols, rows * inner * cols, |band, c| {
+        let first_row = band * ROWS_PER_BAND;
+        for col_block in (0..cols).step_by(NC) {
+            let col_end = (col_block + NC).min(cols);
+            for inner_block in (0..inner).step_by(KC) {
+                let inner_end = (inner_block + KC).min(inner);
+
+                for (row, c_row) in c.chunks_exact_mut(cols).enumerate() {
+                    let a_row = &a[(first_row + row) * inner..][..inner];
+                    let c_row = &mut c_row[col_block..col_end];
+                    for k in inner_block..inner_end {
+                        let b_row = &b[k * cols + col_block..k * cols + col_end];
+                        axpy(alpha * a_row[k], b_row, c_row);
+                    }
+                }
+            }
+        }
+    });
+}
+
+#[cfg(feature = "alloc")]
+impl DMat {
+    /// Computes `c = alpha * a * b + beta * c` in place, without allocating.
+    ///
+    /// As with BLAS, `c` is not read when `beta` is zero, so NaN or infinite values in it are discarded.
+    pub fn gemm(alpha: f64, a: &DMat, b: &DMat, beta: f64, c: &mut DMat) {
+        assert!(
+            a.cols() == b.rows(),
+            "Attempted to multiply two non-commutative matrices"
+        );
+        assert!(
+            (c.rows(), c.cols()) == (a.rows(), b.cols()),
+            "Attempted to accumulate a matrix product into a matrix of the wrong size"
+        );
+
+        let dimensions = (a.rows(), a.cols(), b.cols());
+        gemm_slices(
+            alpha,
+            a.as_slice(),
+            b.as_slice(),
+            dimensions,
+            beta,
+            c.as_mut_slice(),
+        );
+    }
+}
@@ -3,8 +3,46 @@
 #![allow(clippy::module_name_repetitions)]
 #![allow(clippy::missing_panics_doc)]
 #![no_std]
+#[cfg(feature = "std")]
+extern crate std;
+#[cfg(feature = "alloc")]
+pub mod cdmat;
+pub mod cmat;
+pub mod complex;
+#[cfg(feature = "alloc")]
+pub mod delimited;
+pub mod display;
+#[cfg(feature = "alloc")]
 pub mod dmat;
+#[cfg(feature = "alloc")]
+pub mod dmat_col_major;
+#[cfg(feature = "alloc")]
+pub mod eigen;
+mod elementwise;
+mod gemm;
 pub mod mat;
+#[cfg(feature = "alloc")]
+pub mod matrix_market;
+#[cfg(feature = "alloc")]
+pub mod npy;
+mod parallel;
+#[cfg(feature = "alloc")]
+pub mod parse;
+#[cfg(feature = "alloc")]
+pub mod pca;
+pub mod quaternion;
+mod reductions;
+#[cfg(feature = "alloc")]
+pub mod regression;
+#[cfg(feature = "serde")]
+mod serde_impls;
+pub mod smat;
+#[cfg(feature = "alloc")]
+pub mod stats;
+#[cfg(feature = "alloc")]
+mod strassen;
+pub mod transform;
+pub mod vector;
 
 //no_std f64 abs, helper function used in both Mat and Dmat
 fn f64_abs(x: f64) -> f64 {
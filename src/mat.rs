@@ -1,13 +1,11 @@
-extern crate alloc;
 use crate::f64_abs;
-use alloc::format;
-use alloc::string::{String, ToString};
-use alloc::vec::Vec;
-use core::fmt::Display;
 use core::ops::{
     Add, AddAssign, Deref, DerefMut, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign,
 };
 
+const POLAR_DECOMPOSITION_MAX_ITERATIONS: usize = 100;
+const POLAR_DECOMPOSITION_TOLERANCE: f64 = 1e-14;
+
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub struct Mat<const R: usize, const C: usize>([[f64; C]; R]);
 
@@ -24,43 +22,6 @@ impl<const R: usize, const C: usize> DerefMut for Mat<R, C> {
     }
 }
 
-impl<const R: usize, const C: usize> Display for Mat<R, C> {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let max_column_lengths = (*self.transpose()).map(|column| {
-            column
-                .map(|x| x.to_string().len())
-                .iter()
-                .copied()
-                .max()
-                .unwrap()
-        });
-
-        for (row_index, row) in self.iter().enumerate() {
-            let row_string = row
-                .iter()
-                .enumerate()
-                .map(|(column_index, column)| {
-                    format!(
-                        "{:^len$}",
-                        column.to_string(),
-                        len = max_column_lengths[column_index]
-                    )
-                })
-                .collect::<Vec<String>>()
-                .join(" ");
-            let (start_char, end_char) = match row_index {
-                0 if R == 1 => ("[", "]"),
-                0 => ("┌", "┐\n"),
-                int if int == R - 1 => ("└", "┘"),
-                _ => ("│", "│\n"),
-            };
-            write!(f, "{start_char} {row_string} {end_char}")?;
-        }
-
-        Ok(())
-    }
-}
-
 impl<const R: usize, const C: usize, T: Into<f64>> From<[[T; C]; R]> for Mat<R, C> {
     fn from(value: [[T; C]; R]) -> Self {
         Self(value.map(|row| row.map(|n| n.into())))
@@ -182,6 +143,124 @@ impl<const R: usize, const C: usize> Mat<R, C> {
     pub fn col(&self, col: usize) -> [f64; R] {
         (**self).map(|row| row[col])
     }
+
+    #[must_use]
+    pub fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+        self.iter()
+            .flatten()
+            .zip(other.iter().flatten())
+            .all(|(a, b)| f64_abs(a - b) <= tolerance)
+    }
+}
+
+//const fn counterparts of the constructors and operators, usable in const items
+//they are written with while loops, as iterators and closures cannot be used in const fns
+
+impl<const R: usize, const C: usize> Mat<R, C> {
+    #[must_use]
+    pub const fn new(values: [[f64; C]; R]) -> Self {
+        Self(values)
+    }
+
+    #[must_use]
+    pub const fn as_array(&self) -> &[[f64; C]; R] {
+        &self.0
+    }
+
+    #[must_use]
+    pub const fn const_transpose(&self) -> Mat<C, R> {
+        let mut transpose = Mat::<C, R>::zero();
+        let mut row = 0;
+        while row < R {
+            let mut col = 0;
+            while col < C {
+                transpose.0[col][row] = self.0[row][col];
+                col += 1;
+            }
+            row += 1;
+        }
+        transpose
+    }
+
+    #[must_use]
+    pub const fn const_add(&self, rhs: &Self) -> Self {
+        let mut sum = *self;
+        let mut row = 0;
+        while row < R {
+            let mut col = 0;
+            while col < C {
+                sum.0[row][col] += rhs.0[row][col];
+                col += 1;
+            }
+            row += 1;
+        }
+        sum
+    }
+
+    #[must_use]
+    pub const fn const_sub(&self, rhs: &Self) -> Self {
+        let mut difference = *self;
+        let mut row = 0;
+        while row < R {
+            let mut col = 0;
+            while col < C {
+                difference.0[row][col] -= rhs.0[row][col];
+                col += 1;
+            }
+            row += 1;
+        }
+        difference
+    }
+
+    #[must_use]
+    pub const fn const_scale(&self, scalar: f64) -> Self {
+        let mut scaled = *self;
+        let mut row = 0;
+        while row < R {
+            let mut col = 0;
+            while col < C {
+                scaled.0[row][col] *= scalar;
+                col += 1;
+            }
+            row += 1;
+        }
+        scaled
+    }
+
+    //accumulates each entry in the same order as the * operator, giving bitwise identical results
+    #[must_use]
+    pub const fn const_mul<const C2: usize>(&self, rhs: &Mat<C, C2>) -> Mat<R, C2> {
+        let mut product = Mat::<R, C2>::zero();
+        let mut row = 0;
+        while row < R {
+            let mut col = 0;
+            while col < C2 {
+                let mut dot_product = 0.0;
+                let mut index = 0;
+                while index < C {
+                    dot_product += self.0[row][index] * rhs.0[index][col];
+                    index += 1;
+                }
+                product.0[row][col] = dot_product;
+                col += 1;
+            }
+            row += 1;
+        }
+        product
+    }
+}
+
+impl<const N: usize> Mat<N, N> {
+    #[must_use]
+    pub const fn const_identity() -> Self {
+        let mut identity = Self::zero();
+        let mut index = 0;
+        while index < N {
+            identity.0[index][index] = 1.0;
+            index += 1;
+        }
+        identity
+    }
 }
 
 //functions/operations exclusive to square matrices
@@ -214,6 +293,18 @@ impl<const N: usize> Mat<N, N> {
         *self * self.transpose() == Self::identity()
     }
 
+    //is_orthogonal with each element of A * A^T allowed to differ from the identity by the tolerance
+    #[must_use]
+    pub fn is_orthogonal_within(&self, tolerance: f64) -> bool {
+        (*self * self.transpose()).approx_eq(&Self::identity(), tolerance)
+    }
+
+    //orthogonal with a determinant of 1, i.e. a rotation without reflection
+    #[must_use]
+    pub fn is_rotation_within(&self, tolerance: f64) -> bool {
+        self.is_orthogonal_within(tolerance) && f64_abs(self.determinant() - 1.0) <= tolerance
+    }
+
     #[must_use]
     pub fn is_symmetric(&self) -> bool {
         *self == self.transpose()
@@ -228,23 +319,19 @@ impl<const N: usize> Mat<N, N> {
         let mut transformation_coefficient = 1.0;
 
         for k in 0..N {
-            //find k-th pivot
-            let pivot = reduced
-                .col(k)
-                .iter()
-                .enumerate()
-                .fold(k, |acc, (index, x)| {
-                    if f64_abs(*x) > f64_abs(reduced[acc][k]) {
-                        index
-                    } else {
-                        k
-                    }
-                });
+            //find k-th pivot, the largest element by magnitude on or below the diagonal
+            let pivot = (k..N).fold(k, |acc, index| {
+                if f64_abs(reduced[index][k]) > f64_abs(reduced[acc][k]) {
+                    index
+                } else {
+                    acc
+                }
+            });
 
             if reduced[pivot][k] == 0.0 {
                 //matrix is singular
                 return 0.0;
-            };
+            }
 
             //swap rows, flip transformation coefficient
             if k != pivot {
@@ -302,13 +389,10 @@ impl<const N: usize> Mat<N, N> {
                 }
             }
 
-            //swap columns in the first half of the matrix
+            //swap rows in both halves of the matrix
             if row != i {
-                for col in 0..N {
-                    let temp = augmented[0][row][col];
-                    augmented[0][row][col] = augmented[0][i][col];
-                    augmented[0][i][col] = temp;
-                }
+                augmented[0].swap(row, i);
+                augmented[1].swap(row, i);
             }
 
             //divide current row by pivot element
@@ -339,6 +423,51 @@ impl<const N: usize> Mat<N, N> {
             None
         }
     }
+
+    //orthonormalizes the columns using modified gram-schmidt, keeping the direction of the first column.
+    //returns None if the columns are linearly dependent
+    #[must_use]
+    pub fn orthonormalize(&self) -> Option<Self> {
+        let mut columns = self.transpose();
+
+        for k in 0..N {
+            for j in 0..k {
+                let projection = (0..N).fold(0.0, |acc, i| acc + columns[j][i] * columns[k][i]);
+                for i in 0..N {
+                    columns[k][i] -= projection * columns[j][i];
+                }
+            }
+
+            let norm = libm::sqrt(columns[k].iter().fold(0.0, |acc, n| acc + n * n));
+            if norm == 0.0 {
+                return None;
+            }
+            columns[k] = columns[k].map(|n| n / norm);
+        }
+
+        Some(columns.transpose())
+    }
+
+    //decomposes the matrix into (Q, P) where self = Q * P, Q is the orthogonal matrix closest to self
+    //and P is symmetric positive-definite. returns None if the matrix is singular
+    #[must_use]
+    pub fn polar_decomposition(&self) -> Option<(Self, Self)> {
+        //newton iteration Q <- (Q + Q^-T) / 2, which converges quadratically to the orthogonal factor
+        let mut orthogonal = *self;
+        for _ in 0..POLAR_DECOMPOSITION_MAX_ITERATIONS {
+            let next = (orthogonal + orthogonal.inverse()?.transpose()) * 0.5;
+            let converged = next.approx_eq(&orthogonal, POLAR_DECOMPOSITION_TOLERANCE);
+            orthogonal = next;
+            if converged {
+                break;
+            }
+        }
+
+        let symmetric = orthogonal.transpose() * *self;
+        //symmetrize to remove rounding noise
+        let symmetric = (symmetric + symmetric.transpose()) * 0.5;
+        Some((orthogonal, symmetric))
+    }
 }
 
 impl<const N: usize> MulAssign for Mat<N, N> {
@@ -0,0 +1,516 @@
+extern crate alloc;
+use crate::dmat::{AllocError, DMat};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{Display, Write};
+
+//reading and writing of the Matrix Market exchange format (https://math.nist.gov/MatrixMarket/formats.html)
+//for real, integer and pattern matrices
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum MatrixMarketFormat {
+    //sparse, listing (row, column, value) entries
+    Coordinate,
+    //dense, listing values in column-major order
+    Array,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum MatrixMarketField {
+    Real,
+    Integer,
+    //coordinate entries without values, read as ones
+    Pattern,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum MatrixMarketSymmetry {
+    General,
+    //only the lower triangle is stored, mirrored into the upper triangle
+    Symmetric,
+    //only the strict lower triangle is stored, negated into the upper triangle
+    SkewSymmetric,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct MatrixMarketHeader {
+    pub format: MatrixMarketFormat,
+    pub field: MatrixMarketField,
+    pub symmetry: MatrixMarketSymmetry,
+}
+
+impl MatrixMarketHeader {
+    //header used by DMat::to_matrix_market
+    pub const ARRAY: Self = Self {
+        format: MatrixMarketFormat::Array,
+        field: MatrixMarketField::Real,
+        symmetry: MatrixMarketSymmetry::General,
+    };
+
+    pub const COORDINATE: Self = Self {
+        format: MatrixMarketFormat::Coordinate,
+        field: MatrixMarketField::Real,
+        symmetry: MatrixMarketSymmetry::General,
+    };
+}
+
+impl Display for MatrixMarketHeader {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let format = match self.format {
+            MatrixMarketFormat::Coordinate => "coordinate",
+            MatrixMarketFormat::Array => "array",
+        };
+        let field = match self.field {
+            MatrixMarketField::Real => "real",
+            MatrixMarketField::Integer => "integer",
+            MatrixMarketField::Pattern => "pattern",
+        };
+        let symmetry = match self.symmetry {
+            MatrixMarketSymmetry::General => "general",
+            MatrixMarketSymmetry::Symmetric => "symmetric",
+            MatrixMarketSymmetry::SkewSymmetric => "skew-symmetric",
+        };
+        write!(f, "%%MatrixMarket matrix {format} {field} {symmetry}")
+    }
+}
+
+//line numbers are 1-based
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum MatrixMarketError {
+    MissingHeader,
+    InvalidHeader { line: usize },
+    //a valid header for a matrix type this crate cannot hold, e.g. complex or hermitian
+    UnsupportedHeader { line: usize },
+    MissingSize,
+    //also returned for coordinate files listing more entries than the matrix can hold
+    InvalidSize { line: usize },
+    //an entry that cannot be parsed, or a coordinate entry outside the stored (strict) lower triangle
+    //of a symmetric or skew-symmetric matrix
+    InvalidEntry { line: usize },
+    IndexOutOfBounds { line: usize },
+    //a coordinate entry listed a second time
+    DuplicateEntry { line: usize },
+    WrongEntryCount { expected: usize, found: usize },
+    //the matrix of the size line could not be allocated
+    Alloc(AllocError),
+}
+
+impl Display for MatrixMarketError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::MissingHeader => write!(f, "missing %%MatrixMarket header"),
+            Self::InvalidHeader { line } => write!(f, "invalid header on line {line}"),
+            Self::UnsupportedHeader { line } => {
+                write!(f, "unsupported matrix type in header on line {line}")
+            }
+            Self::MissingSize => write!(f, "missing size line"),
+            Self::InvalidSize { line } => write!(f, "invalid size line on line {line}"),
+            Self::InvalidEntry { line } => write!(f, "invalid entry on line {line}"),
+            Self::IndexOutOfBounds { line } => write!(f, "entry out of bounds on line {line}"),
+            Self::DuplicateEntry { line } => write!(f, "duplicate entry on line {line}"),
+            Self::WrongEntryCount { expected, found } => {
+                write!(f, "expected {expected} entries, found {found}")
+            }
+            Self::Alloc(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl core::error::Error for MatrixMarketError {}
+
+impl From<AllocError> for MatrixMarketError {
+    fn from(error: AllocError) -> Self {
+        Self::Alloc(error)
+    }
+}
+
+//row and column indices are 0-based, as in DMat
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum MatrixMarketWriteError {
+    //pattern matrices can only be written in coordinate format
+    PatternArray,
+    //symmetric and skew-symmetric matrices must be square
+    NotSquare,
+    //an entry that differs from its mirror image as the header's symmetry requires,
+    //or a non-zero diagonal entry of a skew-symmetric matrix
+    NotSymmetric { row: usize, col: usize },
+    //a value that is not an integer in the range of i64, written with an integer header
+    NotInteger { row: usize, col: usize },
+    //the output returned an error
+    Write,
+}
+
+impl Display for MatrixMarketWriteError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::PatternArray => write!(f, "pattern matrices cannot be written in array format"),
+            Self::NotSquare => write!(f, "symmetric matrices must be square"),
+            Self::NotSymmetric { row, col } => write!(
+                f,
+                "entry at row {row}, column {col} does not have the header's symmetry"
+            ),
+            Self::NotInteger { row, col } => write!(
+                f,
+                "entry at row {row}, column {col} is not a 64-bit integer"
+            ),
+            Self::Write => write!(f, "failed to write output"),
+        }
+    }
+}
+
+impl core::error::Error for MatrixMarketWriteError {}
+
+impl From<core::fmt::Error> for MatrixMarketWriteError {
+    fn from(_: core::fmt::Error) -> Self {
+        Self::Write
+    }
+}
+
+fn parse_header(line: &str, line_number: usize) -> Result<MatrixMarketHeader, MatrixMarketError> {
+    let invalid = MatrixMarketError::InvalidHeader { line: line_number };
+    let unsupported = MatrixMarketError::UnsupportedHeader { line: line_number };
+
+    let mut words = line.split_whitespace();
+    if !words
+        .next()
+        .is_some_and(|word| word.eq_ignore_ascii_case("%%MatrixMarket"))
+    {
+        return Err(MatrixMarketError::MissingHeader);
+    }
+
+    let [Some(object), Some(format), Some(field), Some(symmetry), None] =
+        [(); 5].map(|()| words.next())
+    else {
+        return Err(invalid);
+    };
+
+    if !object.eq_ignore_ascii_case("matrix") {
+        return Err(unsupported);
+    }
+
+    let format = match format.to_ascii_lowercase().as_str() {
+        "coordinate" => MatrixMarketFormat::Coordinate,
+        "array" => MatrixMarketFormat::Array,
+        _ => return Err(invalid),
+    };
+    let field = match field.to_ascii_lowercase().as_str() {
+        "real" | "double" => MatrixMarketField::Real,
+        "integer" => MatrixMarketField::Integer,
+        "pattern" if format == MatrixMarketFormat::Coordinate => MatrixMarketField::Pattern,
+        "complex" => return Err(unsupported),
+        _ => return Err(invalid),
+    };
+    let symmetry = match symmetry.to_ascii_lowercase().as_str() {
+        "general" => MatrixMarketSymmetry::General,
+        "symmetric" => MatrixMarketSymmetry::Symmetric,
+        "skew-symmetric" => MatrixMarketSymmetry::SkewSymmetric,
+        "hermitian" => return Err(unsupported),
+        _ => return Err(invalid),
+    };
+
+    Ok(MatrixMarketHeader {
+        format,
+        field,
+        symmetry,
+    })
+}
+
+//rows, columns and number of entries listed in the file
+fn parse_size(
+    line: &str,
+    line_number: usize,
+    header: MatrixMarketHeader,
+) -> Result<(usize, usize, usize), MatrixMarketError> {
+    let invalid_size = MatrixMarketError::InvalidSize { line: line_number };
+    let size = line
+        .split_whitespace()
+        .map(|word| word.parse::<usize>().map_err(|_| invalid_size.clone()))
+        .collect::<Result<Vec<usize>, _>>()?;
+
+    let (&[rows, cols] | &[rows, cols, _]) = size.as_slice() else {
+        return Err(invalid_size);
+    };
+    //number of values in the stored part of the matrix
+    let stored = match header.symmetry {
+        MatrixMarketSymmetry::General => rows.checked_mul(cols),
+        MatrixMarketSymmetry::Symmetric if rows == cols => rows
+            .checked_add(1)
+            .and_then(|n| rows.checked_mul(n))
+            .map(|n| n / 2),
+        MatrixMarketSymmetry::SkewSymmetric if rows == cols => {
+            rows.checked_mul(rows.saturating_sub(1)).map(|n| n / 2)
+        }
+        _ => None,
+    }
+    .ok_or(invalid_size.clone())?;
+
+    match (header.format, size.as_slice()) {
+        (MatrixMarketFormat::Coordinate, &[_, _, entries]) if entries <= stored => {
+            Ok((rows, cols, entries))
+        }
+        (MatrixMarketFormat::Array, &[_, _]) => Ok((rows, cols, stored)),
+        _ => Err(invalid_size),
+    }
+}
+
+//line of the first repeated entry, given the (row, col, line) of every entry
+fn find_duplicate(positions: &mut [(usize, usize, usize)]) -> Option<usize> {
+    positions.sort_unstable();
+    positions
+        .windows(2)
+        .filter(|pair| (pair[0].0, pair[0].1) == (pair[1].0, pair[1].1))
+        .map(|pair| pair[1].2)
+        .min()
+}
+
+//bounds of the values representable as i64, as read by parse_value
+#[allow(clippy::cast_precision_loss)]
+const INTEGER_RANGE: core::ops::Range<f64> = i64::MIN as f64..-(i64::MIN as f64);
+
+#[allow(clippy::cast_precision_loss)]
+fn parse_value(word: Option<&str>, field: MatrixMarketField) -> Option<f64> {
+    match field {
+        MatrixMarketField::Real => word?.parse().ok(),
+        //parse as an integer to reject non-integer values
+        MatrixMarketField::Integer => word?.parse::<i64>().ok().map(|n| n as f64),
+        MatrixMarketField::Pattern => Some(1.0),
+    }
+}
+
+impl DMat {
+    /// Parses a Matrix Market file, expanding symmetric and skew-symmetric storage into the full matrix.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `MatrixMarketError` if the input is not a valid real, integer or pattern
+    /// Matrix Market matrix, which includes coordinate entries listed twice or outside the stored
+    /// triangle, or if the matrix of the size line cannot be allocated.
+    pub fn from_matrix_market(input: &str) -> Result<Self, MatrixMarketError> {
+        let mut lines = input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| (index + 1, line.trim()));
+
+        let (header_line, header) = lines.next().ok_or(MatrixMarketError::MissingHeader)?;
+        let header = parse_header(header, header_line)?;
+
+        //skip comments and blank lines
+        let mut lines = lines.filter(|(_, line)| !line.is_empty() && !line.starts_with('%'));
+
+        let (size_line, size) = lines.next().ok_or(MatrixMarketError::MissingSize)?;
+        let (rows, cols, entries) = parse_size(size, size_line, header)?;
+
+        let mut mat = Self::try_zero(rows, cols)?;
+        let mut set = |row: usize, col: usize, value: f64| {
+            mat[row][col] = value;
+            match header.symmetry {
+                MatrixMarketSymmetry::General => {}
+                MatrixMarketSymmetry::Symmetric => mat[col][row] = value,
+                MatrixMarketSymmetry::SkewSymmetric => mat[col][row] = -value,
+            }
+        };
+
+        //array entries are listed in column-major order, over the stored triangle for symmetric matrices
+        let mut array_position = match header.symmetry {
+            MatrixMarketSymmetry::SkewSymmetric => (1, 0),
+            _ => (0, 0),
+        };
+        let mut found = 0;
+        //(row, col, line) of every coordinate entry, to find duplicates
+        let mut positions = Vec::new();
+
+        for (line_number, line) in lines {
+            if found == entries {
+                return Err(MatrixMarketError::WrongEntryCount {
+                    expected: entries,
+                    found: found + 1,
+                });
+            }
+
+            let invalid_entry = MatrixMarketError::InvalidEntry { line: line_number };
+            let mut words = line.split_whitespace();
+
+            let (row, col) = match header.format {
+                MatrixMarketFormat::Coordinate => {
+                    let mut index = || {
+                        words
+                            .next()
+                            .and_then(|word| word.parse::<usize>().ok())
+                            .ok_or(invalid_entry.clone())
+                    };
+                    let (row, col) = (index()?, index()?);
+                    //indices are 1-based
+                    if row == 0 || col == 0 || row > rows || col > cols {
+                        return Err(MatrixMarketError::IndexOutOfBounds { line: line_number });
+                    }
+                    let (row, col) = (row - 1, col - 1);
+                    let stored = match header.symmetry {
+                        MatrixMarketSymmetry::General => true,
+                        MatrixMarketSymmetry::Symmetric => row >= col,
+                        MatrixMarketSymmetry::SkewSymmetric => row > col,
+                    };
+                    if !stored {
+                        return Err(invalid_entry);
+                    }
+                    positions.push((row, col, line_number));
+                    (row, col)
+                }
+                MatrixMarketFormat::Array => array_position,
+            };
+
+            let value = parse_value(words.next(), header.field).ok_or(invalid_entry.clone())?;
+            if words.next().is_some() {
+                return Err(invalid_entry);
+            }
+
+            set(row, col, value);
+            found += 1;
+
+            if header.format == MatrixMarketFormat::Array {
+                let (mut row, mut col) = array_position;
+                row += 1;
+                if row == rows {
+                    col += 1;
+                    row = match header.symmetry {
+                        MatrixMarketSymmetry::General => 0,
+                        MatrixMarketSymmetry::Symmetric => col,
+                        MatrixMarketSymmetry::SkewSymmetric => col + 1,
+                    };
+                }
+                array_position = (row, col);
+            }
+        }
+
+        if found != entries {
+            return Err(MatrixMarketError::WrongEntryCount {
+                expected: entries,
+                found,
+            });
+        }
+
+        if let Some(line) = find_duplicate(&mut positions) {
+            return Err(MatrixMarketError::DuplicateEntry { line });
+        }
+
+        Ok(mat)
+    }
+
+    /// Writes the matrix in Matrix Market format. Coordinate output lists only non-zero entries,
+    /// and symmetric or skew-symmetric output only lists the (strict) lower triangle.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `MatrixMarketWriteError` if the header cannot hold the matrix, as for a matrix
+    /// without the header's symmetry (or, for pattern headers, without its symmetric structure)
+    /// or with non-integer values for an integer header, or if writing to the output fails.
+    /// Nothing is written if the header cannot hold the matrix.
+    #[allow(clippy::float_cmp)]
+    pub fn write_matrix_market<W: Write>(
+        &self,
+        output: &mut W,
+        header: &MatrixMarketHeader,
+    ) -> Result<(), MatrixMarketWriteError> {
+        if header.format == MatrixMarketFormat::Array && header.field == MatrixMarketField::Pattern
+        {
+            return Err(MatrixMarketWriteError::PatternArray);
+        }
+        if header.symmetry != MatrixMarketSymmetry::General && self.rows() != self.cols() {
+            return Err(MatrixMarketWriteError::NotSquare);
+        }
+
+        //the upper triangle is not written, so it must be recoverable from the lower one
+        for row in 0..self.rows() {
+            for col in 0..=row.min(self.cols().saturating_sub(1)) {
+                let (value, mirror) = (self[row][col], self[col][row]);
+                let symmetric = match (header.symmetry, header.field) {
+                    (MatrixMarketSymmetry::General, _) => true,
+                    (MatrixMarketSymmetry::Symmetric, MatrixMarketField::Pattern) => {
+                        (value == 0.0) == (mirror == 0.0)
+                    }
+                    //NaN values mirror each other
+                    (MatrixMarketSymmetry::Symmetric, _) => {
+                        value == mirror || (value.is_nan() && mirror.is_nan())
+                    }
+                    (MatrixMarketSymmetry::SkewSymmetric, MatrixMarketField::Pattern) => {
+                        (value == 0.0) == (mirror == 0.0) && (row != col || value == 0.0)
+                    }
+                    (MatrixMarketSymmetry::SkewSymmetric, _) => {
+                        value == -mirror || (row != col && value.is_nan() && mirror.is_nan())
+                    }
+                };
+                if !symmetric {
+                    return Err(MatrixMarketWriteError::NotSymmetric { row, col });
+                }
+            }
+        }
+
+        if header.field == MatrixMarketField::Integer {
+            let integer =
+                |value: f64| libm::trunc(value) == value && INTEGER_RANGE.contains(&value);
+            for row in 0..self.rows() {
+                for col in 0..self.cols() {
+                    if !integer(self[row][col]) {
+                        return Err(MatrixMarketWriteError::NotInteger { row, col });
+                    }
+                }
+            }
+        }
+
+        //positions written, in column-major order
+        let stored = |row: usize, col: usize| match header.symmetry {
+            MatrixMarketSymmetry::General => true,
+            MatrixMarketSymmetry::Symmetric => row >= col,
+            MatrixMarketSymmetry::SkewSymmetric => row > col,
+        };
+        let positions = (0..self.cols())
+            .flat_map(|col| (0..self.rows()).map(move |row| (row, col)))
+            .filter(|&(row, col)| stored(row, col))
+            .filter(|&(row, col)| {
+                header.format == MatrixMarketFormat::Array || self[row][col] != 0.0
+            });
+
+        writeln!(output, "{header}")?;
+        match header.format {
+            MatrixMarketFormat::Coordinate => writeln!(
+                output,
+                "{} {} {}",
+                self.rows(),
+                self.cols(),
+                positions.clone().count()
+            )?,
+            MatrixMarketFormat::Array => writeln!(output, "{} {}", self.rows(), self.cols())?,
+        }
+
+        for (row, col) in positions {
+            let value = self[row][col];
+            if header.format == MatrixMarketFormat::Coordinate {
+                write!(output, "{} {}", row + 1, col + 1)?;
+                if header.field == MatrixMarketField::Pattern {
+                    writeln!(output)?;
+                    continue;
+                }
+                write!(output, " ")?;
+            }
+
+            //f64 Display rounds large integers to their shortest representation, which can differ
+            //from the integer itself (or be out of range of i64)
+            if header.field == MatrixMarketField::Integer {
+                #[allow(clippy::cast_possible_truncation)]
+                writeln!(output, "{}", value as i64)?;
+            } else {
+                writeln!(output, "{value}")?;
+            }
+        }
+
+        Ok(())
+    }
+
+    //writes the matrix as a dense, general, real Matrix Market array
+    #[must_use]
+    pub fn to_matrix_market(&self) -> String {
+        let mut output = String::new();
+        self.write_matrix_market(&mut output, &MatrixMarketHeader::ARRAY)
+            .expect("Writing to a String cannot fail");
+        output
+    }
+}
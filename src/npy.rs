@@ -0,0 +1,375 @@
+extern crate alloc;
+use crate::dmat::DMat;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use core::fmt::Display;
+
+//reading and writing of NumPy .npy buffers (https://numpy.org/doc/stable/reference/generated/numpy.lib.format.html)
+//holding 2-D f64 or f32 arrays
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+//total header length (magic, version, length and dict) is padded to a multiple of this
+const HEADER_ALIGNMENT: usize = 64;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum NpyDtype {
+    //'<f8'
+    F64Le,
+    //'>f8'
+    F64Be,
+    //'<f4'
+    F32Le,
+    //'>f4'
+    F32Be,
+}
+
+impl NpyDtype {
+    fn descr(self) -> &'static str {
+        match self {
+            Self::F64Le => "<f8",
+            Self::F64Be => ">f8",
+            Self::F32Le => "<f4",
+            Self::F32Be => ">f4",
+        }
+    }
+
+    fn from_descr(descr: &str) -> Option<Self> {
+        //'=' is native byte order
+        let native = if cfg!(target_endian = "little") {
+            '<'
+        } else {
+            '>'
+        };
+        let descr = descr
+            .strip_prefix('=')
+            .map_or(String::from(descr), |size| format!("{native}{size}"));
+
+        match descr.as_str() {
+            "<f8" => Some(Self::F64Le),
+            ">f8" => Some(Self::F64Be),
+            "<f4" => Some(Self::F32Le),
+            ">f4" => Some(Self::F32Be),
+            _ => None,
+        }
+    }
+
+    fn size(self) -> usize {
+        match self {
+            Self::F64Le | Self::F64Be => 8,
+            Self::F32Le | Self::F32Be => 4,
+        }
+    }
+
+    fn read(self, bytes: &[u8]) -> f64 {
+        match self {
+            Self::F64Le => f64::from_le_bytes(bytes.try_into().unwrap()),
+            Self::F64Be => f64::from_be_bytes(bytes.try_into().unwrap()),
+            Self::F32Le => f64::from(f32::from_le_bytes(bytes.try_into().unwrap())),
+            Self::F32Be => f64::from(f32::from_be_bytes(bytes.try_into().unwrap())),
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn write(self, value: f64, output: &mut Vec<u8>) {
+        match self {
+            Self::F64Le => output.extend_from_slice(&value.to_le_bytes()),
+            Self::F64Be => output.extend_from_slice(&value.to_be_bytes()),
+            Self::F32Le => output.extend_from_slice(&(value as f32).to_le_bytes()),
+            Self::F32Be => output.extend_from_slice(&(value as f32).to_be_bytes()),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum NpyOrder {
+    //row-major
+    C,
+    //column-major
+    Fortran,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum NpyError {
+    InvalidMagic,
+    UnsupportedVersion { major: u8, minor: u8 },
+    //the buffer ends before the header or data it declares
+    UnexpectedEnd,
+    InvalidHeader(String),
+    UnsupportedDtype(String),
+    UnsupportedShape(Vec<usize>),
+    DataLength { expected: usize, found: usize },
+}
+
+impl Display for NpyError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidMagic => write!(f, "not a .npy buffer: missing \\x93NUMPY magic string"),
+            Self::UnsupportedVersion { major, minor } => {
+                write!(f, "unsupported .npy format version {major}.{minor}")
+            }
+            Self::UnexpectedEnd => write!(f, "unexpected end of .npy buffer"),
+            Self::InvalidHeader(reason) => write!(f, "invalid .npy header: {reason}"),
+            Self::UnsupportedDtype(descr) => write!(
+                f,
+                "unsupported dtype '{descr}', expected one of '<f8', '>f8', '<f4' or '>f4'"
+            ),
+            Self::UnsupportedShape(shape) => {
+                write!(
+                    f,
+                    "unsupported shape {shape:?}, only 2-D arrays can be read"
+                )
+            }
+            Self::DataLength { expected, found } => {
+                write!(f, "expected {expected} bytes of data, found {found}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for NpyError {}
+
+//values of the python dict literal in a .npy header
+enum HeaderValue<'a> {
+    Str(&'a str),
+    Bool(bool),
+    Tuple(Vec<usize>),
+}
+
+struct HeaderParser<'a> {
+    header: &'a str,
+    position: usize,
+}
+
+impl<'a> HeaderParser<'a> {
+    fn error(&self, reason: &str) -> NpyError {
+        NpyError::InvalidHeader(format!("{reason} at offset {}", self.position))
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.header[self.position..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    //consumes the given token if it comes next, ignoring leading whitespace
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(token) {
+            self.position += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, token: &str) -> Result<(), NpyError> {
+        if self.eat(token) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected '{token}'")))
+        }
+    }
+
+    fn string(&mut self) -> Result<&'a str, NpyError> {
+        self.skip_whitespace();
+        let Some(quote @ ('\'' | '"')) = self.rest().chars().next() else {
+            return Err(self.error("expected a string"));
+        };
+        let rest = &self.rest()[1..];
+        let end = rest
+            .find(quote)
+            .ok_or_else(|| self.error("unterminated string"))?;
+        self.position += end + 2;
+        Ok(&rest[..end])
+    }
+
+    fn value(&mut self) -> Result<HeaderValue<'a>, NpyError> {
+        if self.eat("True") {
+            return Ok(HeaderValue::Bool(true));
+        }
+        if self.eat("False") {
+            return Ok(HeaderValue::Bool(false));
+        }
+        if !self.eat("(") {
+            return self.string().map(HeaderValue::Str);
+        }
+
+        let mut tuple = Vec::new();
+        while !self.eat(")") {
+            self.skip_whitespace();
+            let digits = self
+                .rest()
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(self.rest().len());
+            let dimension = self.rest()[..digits]
+                .parse()
+                .map_err(|_| self.error("expected a dimension"))?;
+            self.position += digits;
+            tuple.push(dimension);
+
+            if !self.eat(",") {
+                self.expect(")")?;
+                break;
+            }
+        }
+        Ok(HeaderValue::Tuple(tuple))
+    }
+}
+
+//descr, fortran order and shape from the header dict
+fn parse_header(header: &str) -> Result<(NpyDtype, bool, Vec<usize>), NpyError> {
+    let mut parser = HeaderParser {
+        header,
+        position: 0,
+    };
+    let (mut descr, mut fortran_order, mut shape) = (None, None, None);
+
+    parser.expect("{")?;
+    while !parser.eat("}") {
+        let key = parser.string()?;
+        parser.expect(":")?;
+        match (key, parser.value()?) {
+            ("descr", HeaderValue::Str(value)) => descr = Some(value),
+            ("fortran_order", HeaderValue::Bool(value)) => fortran_order = Some(value),
+            ("shape", HeaderValue::Tuple(value)) => shape = Some(value),
+            ("descr" | "fortran_order" | "shape", _) => {
+                return Err(NpyError::InvalidHeader(format!(
+                    "invalid value for '{key}'"
+                )))
+            }
+            _ => return Err(NpyError::InvalidHeader(format!("unexpected key '{key}'"))),
+        }
+
+        if !parser.eat(",") {
+            parser.expect("}")?;
+            break;
+        }
+    }
+
+    let missing = |key: &str| NpyError::InvalidHeader(format!("missing key '{key}'"));
+    let descr = descr.ok_or_else(|| missing("descr"))?;
+    let dtype =
+        NpyDtype::from_descr(descr).ok_or_else(|| NpyError::UnsupportedDtype(descr.to_string()))?;
+
+    Ok((
+        dtype,
+        fortran_order.ok_or_else(|| missing("fortran_order"))?,
+        shape.ok_or_else(|| missing("shape"))?,
+    ))
+}
+
+impl DMat {
+    /// Reads a 2-D array of `f64` or `f32` values, in either byte order and memory layout, from a `.npy` buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `NpyError` if the buffer is not a valid `.npy` file, or holds an array
+    /// that is not 2-D or not made of `f64`/`f32` values.
+    pub fn from_npy(bytes: &[u8]) -> Result<Self, NpyError> {
+        if !bytes.starts_with(MAGIC) {
+            return Err(NpyError::InvalidMagic);
+        }
+
+        let version = bytes.get(6..8).ok_or(NpyError::UnexpectedEnd)?;
+        //version 1.0 stores the header length in 2 bytes, later versions in 4
+        let (header_start, header_len) = match (version[0], version[1]) {
+            (1, 0) => {
+                let len = bytes.get(8..10).ok_or(NpyError::UnexpectedEnd)?;
+                (10, usize::from(u16::from_le_bytes([len[0], len[1]])))
+            }
+            (2 | 3, 0) => {
+                let len = bytes.get(8..12).ok_or(NpyError::UnexpectedEnd)?;
+                let len = u32::from_le_bytes([len[0], len[1], len[2], len[3]]);
+                (
+                    12,
+                    usize::try_from(len).map_err(|_| NpyError::UnexpectedEnd)?,
+                )
+            }
+            (major, minor) => return Err(NpyError::UnsupportedVersion { major, minor }),
+        };
+
+        let data_start = header_start + header_len;
+        let header = bytes
+            .get(header_start..data_start)
+            .ok_or(NpyError::UnexpectedEnd)?;
+        let header = core::str::from_utf8(header)
+            .map_err(|_| NpyError::InvalidHeader("header is not valid text".to_string()))?;
+
+        let (dtype, fortran_order, shape) = parse_header(header)?;
+        let &[rows, cols] = shape.as_slice() else {
+            return Err(NpyError::UnsupportedShape(shape));
+        };
+
+        let data = &bytes[data_start..];
+        let expected = rows
+            .checked_mul(cols)
+            .and_then(|len| len.checked_mul(dtype.size()))
+            .ok_or_else(|| NpyError::UnsupportedShape(vec![rows, cols]))?;
+        if data.len() != expected {
+            return Err(NpyError::DataLength {
+                expected,
+                found: data.len(),
+            });
+        }
+
+        let value = |index: usize| dtype.read(&data[index * dtype.size()..][..dtype.size()]);
+        Ok(if fortran_order {
+            Self::generate(rows, cols, |row, col| value(col * rows + row))
+        } else {
+            Self::generate(rows, cols, |row, col| value(row * cols + col))
+        })
+    }
+
+    //writes the matrix as a version 1.0 .npy buffer, converting values to the given dtype and layout
+    #[must_use]
+    pub fn to_npy(&self, dtype: NpyDtype, order: NpyOrder) -> Vec<u8> {
+        let fortran_order = if order == NpyOrder::Fortran {
+            "True"
+        } else {
+            "False"
+        };
+        let mut header = format!(
+            "{{'descr': '{}', 'fortran_order': {fortran_order}, 'shape': ({}, {}), }}",
+            dtype.descr(),
+            self.rows(),
+            self.cols()
+        );
+
+        //pad with spaces and a final newline so that the data is aligned
+        let unpadded_len = MAGIC.len() + 4 + header.len() + 1;
+        let padding = (HEADER_ALIGNMENT - unpadded_len % HEADER_ALIGNMENT) % HEADER_ALIGNMENT;
+        header.extend(core::iter::repeat_n(' ', padding));
+        header.push('\n');
+
+        let header_len =
+            u16::try_from(header.len()).expect("Attempted to write an oversized .npy header");
+
+        let mut output = vec![];
+        output.extend_from_slice(MAGIC);
+        output.extend_from_slice(&[1, 0]);
+        output.extend_from_slice(&header_len.to_le_bytes());
+        output.extend_from_slice(header.as_bytes());
+        output.reserve(self.rows() * self.cols() * dtype.size());
+
+        match order {
+            NpyOrder::C => {
+                for &value in self.as_slice() {
+                    dtype.write(value, &mut output);
+                }
+            }
+            NpyOrder::Fortran => {
+                for col in 0..self.cols() {
+                    for row in 0..self.rows() {
+                        dtype.write(self[row][col], &mut output);
+                    }
+                }
+            }
+        }
+
+        output
+    }
+}
@@ -0,0 +1,79 @@
+#[cfg(feature = "parallel")]
+extern crate alloc;
+#[cfg(feature = "parallel")]
+use crate::dmat::{checked_len, DMat};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+//multithreaded execution of DMat kernels when the parallel feature is enabled
+//
+//products (gemm bands), transpose_into, the elimination steps of determinants and inverses, and
+//generate, map and mutate run on multiple threads once they reach PARALLEL_THRESHOLD. as the closures
+//of generate, map and mutate then run on other threads, the feature adds a Sync bound to them.
+//par_generate, par_map and par_mutate use multiple threads whatever the size of the matrix.
+//the eigen, statistics and regression routines are only parallel through the kernels they call
+//
+//work is always split into the same fixed-size chunks, whether they run on one thread or many,
+//and every value is computed by a single task in the same order as the serial kernels,
+//so results are bitwise identical regardless of the number of threads
+
+//kernels with fewer multiply-adds (or values, for elementwise kernels) than this run on the calling thread
+#[cfg(feature = "parallel")]
+pub(crate) const PARALLEL_THRESHOLD: usize = 1 << 15;
+
+//runs f on every chunk of chunk_len values (the last one possibly shorter), along with its index,
+//in parallel if the parallel feature is enabled and work reaches PARALLEL_THRESHOLD
+pub(crate) fn for_each_chunk<F: Fn(usize, &mut [f64]) + Sync + Send>(
+    vals: &mut [f64],
+    chunk_len: usize,
+    work: usize,
+    f: F,
+) {
+    if chunk_len == 0 {
+        return;
+    }
+
+    #[cfg(feature = "parallel")]
+    if work >= PARALLEL_THRESHOLD {
+        vals.par_chunks_mut(chunk_len)
+            .enumerate()
+            .for_each(|(index, chunk)| f(index, chunk));
+        return;
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    let _ = work;
+
+    vals.chunks_mut(chunk_len)
+        .enumerate()
+        .for_each(|(index, chunk)| f(index, chunk));
+}
+
+#[cfg(feature = "parallel")]
+impl DMat {
+    //like generate, evaluating f on multiple threads even below PARALLEL_THRESHOLD
+    #[must_use]
+    pub fn par_generate<F: Fn(usize, usize) -> f64 + Sync>(rows: usize, cols: usize, f: F) -> Self {
+        let mut vals = alloc::vec![0.0; checked_len(rows, cols)];
+        vals.par_iter_mut().enumerate().for_each(|(index, val)| {
+            *val = f(index / cols, index % cols);
+        });
+        Self::from_vec(rows, cols, vals)
+    }
+
+    //like map, evaluating f on multiple threads even below PARALLEL_THRESHOLD
+    #[must_use]
+    pub fn par_map<F: Fn(f64) -> f64 + Sync>(&self, f: F) -> Self {
+        let vals = self.as_slice().par_iter().map(|&val| f(val)).collect();
+        Self::from_vec(self.rows(), self.cols(), vals)
+    }
+
+    //like mutate, evaluating f on multiple threads even below PARALLEL_THRESHOLD
+    pub fn par_mutate<F: Fn(f64, usize, usize) -> f64 + Sync>(&mut self, f: F) {
+        let cols = self.cols();
+        self.as_mut_slice()
+            .par_iter_mut()
+            .enumerate()
+            .for_each(|(index, val)| *val = f(*val, index / cols, index % cols));
+    }
+}
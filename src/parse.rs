@@ -0,0 +1,176 @@
+extern crate alloc;
+use crate::dmat::DMat;
+use crate::mat::Mat;
+use alloc::vec::Vec;
+use core::fmt::Display;
+use core::str::FromStr;
+
+//parsing of matrix literals, accepting:
+//- MATLAB style literals, with rows separated by semicolons or newlines: "[1 2 3; 4 5 6]"
+//- the mat! macro syntax, with or without the macro name: "1, 2, 3; 4, 5, 6" or "mat![1, 2, 3; 4, 5, 6]"
+//- the box-drawn output of Display for Mat and DMat
+//values may be separated by commas, whitespace or both
+//
+//Display output of a matrix with elided rows or columns no longer holds every value and is rejected,
+//only output within the display limits (or written with DisplayOptions::UNLIMITED) can be parsed back
+
+const MACRO_NAMES: [&str; 2] = ["dmat!", "mat!"];
+const BOX_LEFT: [char; 3] = ['┌', '│', '└'];
+const BOX_RIGHT: [char; 3] = ['┐', '│', '┘'];
+//written by Display in place of elided rows, columns or both
+const ELLIPSES: [char; 3] = ['⋮', '…', '⋱'];
+
+//rows and columns are 1-based positions within the matrix
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ParseMatError {
+    //the input holds no values
+    Empty,
+    //an opening bracket without a closing bracket or the other way around
+    UnbalancedBrackets,
+    //Display output with elided rows or columns
+    Elided,
+    InvalidValue {
+        row: usize,
+        column: usize,
+    },
+    //a row holding a different number of values than the first row
+    RaggedRow {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    //the input does not match the dimensions of the Mat being parsed
+    WrongDimensions {
+        expected: (usize, usize),
+        found: (usize, usize),
+    },
+}
+
+impl Display for ParseMatError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "matrix holds no values"),
+            Self::UnbalancedBrackets => write!(f, "unbalanced brackets"),
+            Self::Elided => write!(f, "matrix has elided rows or columns"),
+            Self::InvalidValue { row, column } => {
+                write!(f, "invalid value on row {row}, column {column}")
+            }
+            Self::RaggedRow {
+                row,
+                expected,
+                found,
+            } => write!(f, "expected {expected} values on row {row}, found {found}"),
+            Self::WrongDimensions { expected, found } => write!(
+                f,
+                "expected a {}x{} matrix, found {}x{}",
+                expected.0, expected.1, found.0, found.1
+            ),
+        }
+    }
+}
+
+impl core::error::Error for ParseMatError {}
+
+//parses the values of a single row, returning an error holding the 1-based column of an invalid value
+fn parse_row(row: &str, values: &mut Vec<f64>) -> Result<usize, usize> {
+    let mut count = 0;
+
+    for part in row.split(',') {
+        let mut words = part.split_whitespace().peekable();
+        //values cannot be left out between commas
+        if words.peek().is_none() {
+            return Err(count + 1);
+        }
+        for word in words {
+            count += 1;
+            values.push(word.parse().map_err(|_| count)?);
+        }
+    }
+
+    Ok(count)
+}
+
+fn parse(input: &str) -> Result<DMat, ParseMatError> {
+    let mut input = input.trim();
+    if let Some(rest) = MACRO_NAMES.iter().find_map(|name| input.strip_prefix(name)) {
+        input = rest.trim_start();
+    }
+
+    if input.contains(ELLIPSES) {
+        return Err(ParseMatError::Elided);
+    }
+
+    let rows: Vec<&str> = if input.starts_with(BOX_LEFT) {
+        //each line of Display output is a row enclosed in box-drawing characters
+        input
+            .lines()
+            .map(|line| {
+                line.trim()
+                    .trim_start_matches(BOX_LEFT)
+                    .trim_end_matches(BOX_RIGHT)
+            })
+            .collect()
+    } else {
+        let input = match (input.strip_prefix('['), input.ends_with(']')) {
+            (Some(rest), true) => &rest[..rest.len() - 1],
+            (None, false) => input,
+            _ => return Err(ParseMatError::UnbalancedBrackets),
+        };
+        if input.contains(['[', ']']) {
+            return Err(ParseMatError::UnbalancedBrackets);
+        }
+        input.split([';', '\n']).collect()
+    };
+
+    let mut values = Vec::new();
+    let mut cols = 0;
+    let mut row_count = 0;
+
+    //blank rows, such as those left by a trailing semicolon, are skipped
+    for row in rows.into_iter().filter(|row| !row.trim().is_empty()) {
+        row_count += 1;
+        let found = parse_row(row, &mut values).map_err(|column| ParseMatError::InvalidValue {
+            row: row_count,
+            column,
+        })?;
+
+        if row_count == 1 {
+            cols = found;
+        } else if found != cols {
+            return Err(ParseMatError::RaggedRow {
+                row: row_count,
+                expected: cols,
+                found,
+            });
+        }
+    }
+
+    if row_count == 0 {
+        return Err(ParseMatError::Empty);
+    }
+
+    Ok(DMat::from_vec(row_count, cols, values))
+}
+
+impl FromStr for DMat {
+    type Err = ParseMatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse(s)
+    }
+}
+
+impl<const R: usize, const C: usize> FromStr for Mat<R, C> {
+    type Err = ParseMatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mat = parse(s)?;
+        if (mat.rows(), mat.cols()) != (R, C) {
+            return Err(ParseMatError::WrongDimensions {
+                expected: (R, C),
+                found: (mat.rows(), mat.cols()),
+            });
+        }
+        Ok(Self::generate(|row, col| mat[row][col]))
+    }
+}
@@ -0,0 +1,127 @@
+extern crate alloc;
+use crate::dmat::DMat;
+use alloc::vec::Vec;
+
+//number of principal components kept by Pca::fit
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum PcaComponents {
+    //as many components as there are variables
+    All,
+    //the given number of components, or all of them if there are fewer variables
+    Count(usize),
+    //the fewest components whose explained variance ratios add up to at least the given fraction,
+    //none if the data has no variance
+    VarianceThreshold(f64),
+}
+
+//principal component analysis of a matrix of observations (rows) of variables (columns),
+//from the symmetric eigendecomposition of their sample covariance matrix
+//components are sorted by decreasing explained variance
+#[derive(Debug, PartialEq, Clone)]
+pub struct Pca {
+    //1 x variables row of the means subtracted before projecting
+    mean: DMat,
+    //components x variables, each row being a unit principal axis
+    components: DMat,
+    explained_variance: Vec<f64>,
+    explained_variance_ratio: Vec<f64>,
+}
+
+impl Pca {
+    //returns None if the eigendecomposition does not converge, as when there are NaN values
+    //or fewer than 2 observations
+    //
+    //when the data has no variance at all (every variable is constant), every explained variance
+    //ratio is 0 rather than NaN, and a VarianceThreshold keeps no components, as none are needed
+    #[must_use]
+    pub fn fit(data: &DMat, components: PcaComponents) -> Option<Self> {
+        let eigen = data.covariance().symmetric_eigen()?;
+
+        let total_variance: f64 = eigen.values.iter().sum();
+        //a zero total would make every ratio NaN
+        let has_variance = total_variance > 0.0;
+        let ratios: Vec<f64> = eigen
+            .values
+            .iter()
+            .map(|variance| {
+                if has_variance {
+                    variance / total_variance
+                } else {
+                    0.0
+                }
+            })
+            .collect();
+
+        let variables = eigen.values.len();
+        let count = match components {
+            PcaComponents::All => variables,
+            PcaComponents::Count(count) => count.min(variables),
+            PcaComponents::VarianceThreshold(_) if !has_variance => 0,
+            PcaComponents::VarianceThreshold(threshold) => {
+                let mut cumulative = 0.0;
+                ratios
+                    .iter()
+                    .position(|ratio| {
+                        cumulative += ratio;
+                        cumulative >= threshold
+                    })
+                    .map_or(variables, |index| index + 1)
+            }
+        };
+
+        Some(Self {
+            mean: data.mean_cols(),
+            components: DMat::generate(count, variables, |row, col| eigen.vectors[col][row]),
+            explained_variance: eigen.values[..count].to_vec(),
+            explained_variance_ratio: ratios[..count].to_vec(),
+        })
+    }
+
+    #[must_use]
+    pub fn n_components(&self) -> usize {
+        self.components.rows()
+    }
+
+    #[must_use]
+    pub fn mean(&self) -> &DMat {
+        &self.mean
+    }
+
+    #[must_use]
+    pub fn components(&self) -> &DMat {
+        &self.components
+    }
+
+    //variance of the data along each component
+    #[must_use]
+    pub fn explained_variance(&self) -> &[f64] {
+        &self.explained_variance
+    }
+
+    //fraction of the total variance of the data along each component
+    #[must_use]
+    pub fn explained_variance_ratio(&self) -> &[f64] {
+        &self.explained_variance_ratio
+    }
+
+    //projects observations with the fitted variables onto the components
+    #[must_use]
+    pub fn transform(&self, data: &DMat) -> DMat {
+        assert!(
+            data.cols() == self.components.cols(),
+            "Attempted to transform observations with the wrong number of variables"
+        );
+        data.broadcast_sub(&self.mean) * self.components.transpose()
+    }
+
+    //maps projected observations back to the original variables, losing the variance
+    //along the components that were not kept
+    #[must_use]
+    pub fn inverse_transform(&self, projected: &DMat) -> DMat {
+        assert!(
+            projected.cols() == self.components.rows(),
+            "Attempted to inverse transform observations with the wrong number of components"
+        );
+        (projected * &self.components).broadcast_add(&self.mean)
+    }
+}
@@ -0,0 +1,442 @@
+use crate::f64_abs;
+use crate::mat::Mat;
+use crate::vector::Vector;
+use core::f64::consts::PI;
+use core::fmt::Display;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+//tolerance on the middle euler angle under which two axes are considered aligned
+const GIMBAL_LOCK_TOLERANCE: f64 = 1e-7;
+
+//quaternion w + xi + yj + zk, where unit quaternions represent 3D rotations
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Quaternion {
+    pub w: f64,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+//axis sequences for euler angles: the 6 Tait-Bryan sequences (three distinct axes)
+//followed by the 6 proper euler sequences (first and last axes equal)
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EulerOrder {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+    XYX,
+    XZX,
+    YXY,
+    YZY,
+    ZXZ,
+    ZYZ,
+}
+
+impl EulerOrder {
+    //axis indices, with x = 0, y = 1 and z = 2
+    fn axes(self) -> [usize; 3] {
+        match self {
+            Self::XYZ => [0, 1, 2],
+            Self::XZY => [0, 2, 1],
+            Self::YXZ => [1, 0, 2],
+            Self::YZX => [1, 2, 0],
+            Self::ZXY => [2, 0, 1],
+            Self::ZYX => [2, 1, 0],
+            Self::XYX => [0, 1, 0],
+            Self::XZX => [0, 2, 0],
+            Self::YXY => [1, 0, 1],
+            Self::YZY => [1, 2, 1],
+            Self::ZXZ => [2, 0, 2],
+            Self::ZYZ => [2, 1, 2],
+        }
+    }
+}
+
+impl Display for Quaternion {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.w)?;
+        for (value, unit) in [(self.x, "i"), (self.y, "j"), (self.z, "k")] {
+            let sign = if value.is_sign_negative() { '-' } else { '+' };
+            write!(f, " {sign} {}{unit}", f64_abs(value))?;
+        }
+        Ok(())
+    }
+}
+
+impl Add for Quaternion {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.w + rhs.w,
+            self.x + rhs.x,
+            self.y + rhs.y,
+            self.z + rhs.z,
+        )
+    }
+}
+
+impl AddAssign for Quaternion {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Quaternion {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.w - rhs.w,
+            self.x - rhs.x,
+            self.y - rhs.y,
+            self.z - rhs.z,
+        )
+    }
+}
+
+impl SubAssign for Quaternion {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+//hamilton product, where (a * b) applies the rotation b first, then a
+impl Mul for Quaternion {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        let (a, b) = (self, rhs);
+        Self::new(
+            a.w * b.w - a.x * b.x - a.y * b.y - a.z * b.z,
+            a.w * b.x + a.x * b.w + a.y * b.z - a.z * b.y,
+            a.w * b.y - a.x * b.z + a.y * b.w + a.z * b.x,
+            a.w * b.z + a.x * b.y - a.y * b.x + a.z * b.w,
+        )
+    }
+}
+
+impl MulAssign for Quaternion {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Into<f64>> Mul<T> for Quaternion {
+    type Output = Self;
+    fn mul(self, scalar: T) -> Self::Output {
+        let scalar: f64 = scalar.into();
+        Self::new(
+            self.w * scalar,
+            self.x * scalar,
+            self.y * scalar,
+            self.z * scalar,
+        )
+    }
+}
+
+impl<T: Into<f64>> MulAssign<T> for Quaternion {
+    fn mul_assign(&mut self, scalar: T) {
+        *self = *self * scalar;
+    }
+}
+
+impl Neg for Quaternion {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        self * -1
+    }
+}
+
+impl From<Quaternion> for Mat<3, 3> {
+    fn from(value: Quaternion) -> Self {
+        value.to_rotation_matrix()
+    }
+}
+
+impl From<Quaternion> for Mat<4, 4> {
+    fn from(value: Quaternion) -> Self {
+        value.to_homogeneous_matrix()
+    }
+}
+
+impl Quaternion {
+    #[must_use]
+    pub const fn new(w: f64, x: f64, y: f64, z: f64) -> Self {
+        Self { w, x, y, z }
+    }
+
+    #[must_use]
+    pub const fn identity() -> Self {
+        Self::new(1.0, 0.0, 0.0, 0.0)
+    }
+
+    //rotation around an arbitrary axis, which does not need to be normalized
+    #[must_use]
+    pub fn from_axis_angle(axis: Vector<3>, angle: f64) -> Self {
+        let [x, y, z] = axis
+            .normalize()
+            .expect("Attempted to rotate around the zero vector")
+            .to_array();
+        let (sin, cos) = (libm::sin(angle / 2.0), libm::cos(angle / 2.0));
+        Self::new(cos, x * sin, y * sin, z * sin)
+    }
+
+    //returns the rotation axis and an angle in [0, 2pi], using the x axis for the identity rotation
+    #[must_use]
+    pub fn to_axis_angle(&self) -> (Vector<3>, f64) {
+        let q = self.normalize().unwrap_or(Self::identity());
+        let sin = libm::sqrt(q.x * q.x + q.y * q.y + q.z * q.z);
+        let angle = 2.0 * libm::atan2(sin, q.w);
+
+        if sin == 0.0 {
+            (Vector::from_array([1.0, 0.0, 0.0]), 0.0)
+        } else {
+            (Vector::from_array([q.x / sin, q.y / sin, q.z / sin]), angle)
+        }
+    }
+
+    //builds a quaternion from a rotation matrix, normalizing the result to counter drift
+    #[must_use]
+    pub fn from_rotation_matrix(mat: &Mat<3, 3>) -> Self {
+        let m = mat;
+        let trace = m[0][0] + m[1][1] + m[2][2];
+
+        //shepperd's method, dividing by the largest of the four candidate terms for stability
+        let q = if trace > 0.0 {
+            let s = libm::sqrt(trace + 1.0) * 2.0;
+            Self::new(
+                s / 4.0,
+                (m[2][1] - m[1][2]) / s,
+                (m[0][2] - m[2][0]) / s,
+                (m[1][0] - m[0][1]) / s,
+            )
+        } else if m[0][0] > m[1][1] && m[0][0] > m[2][2] {
+            let s = libm::sqrt(1.0 + m[0][0] - m[1][1] - m[2][2]) * 2.0;
+            Self::new(
+                (m[2][1] - m[1][2]) / s,
+                s / 4.0,
+                (m[0][1] + m[1][0]) / s,
+                (m[0][2] + m[2][0]) / s,
+            )
+        } else if m[1][1] > m[2][2] {
+            let s = libm::sqrt(1.0 + m[1][1] - m[0][0] - m[2][2]) * 2.0;
+            Self::new(
+                (m[0][2] - m[2][0]) / s,
+                (m[0][1] + m[1][0]) / s,
+                s / 4.0,
+                (m[1][2] + m[2][1]) / s,
+            )
+        } else {
+            let s = libm::sqrt(1.0 + m[2][2] - m[0][0] - m[1][1]) * 2.0;
+            Self::new(
+                (m[1][0] - m[0][1]) / s,
+                (m[0][2] + m[2][0]) / s,
+                (m[1][2] + m[2][1]) / s,
+                s / 4.0,
+            )
+        };
+
+        q.normalize().unwrap_or(Self::identity())
+    }
+
+    //uses the upper-left rotation block of a homogeneous transform
+    #[must_use]
+    pub fn from_homogeneous_matrix(mat: &Mat<4, 4>) -> Self {
+        Self::from_rotation_matrix(&mat.linear())
+    }
+
+    #[must_use]
+    pub fn to_rotation_matrix(&self) -> Mat<3, 3> {
+        let Self { w, x, y, z } = self.normalize().unwrap_or(Self::identity());
+        Mat::from([
+            [
+                1.0 - 2.0 * (y * y + z * z),
+                2.0 * (x * y - w * z),
+                2.0 * (x * z + w * y),
+            ],
+            [
+                2.0 * (x * y + w * z),
+                1.0 - 2.0 * (x * x + z * z),
+                2.0 * (y * z - w * x),
+            ],
+            [
+                2.0 * (x * z - w * y),
+                2.0 * (y * z + w * x),
+                1.0 - 2.0 * (x * x + y * y),
+            ],
+        ])
+    }
+
+    #[must_use]
+    pub fn to_homogeneous_matrix(&self) -> Mat<4, 4> {
+        Mat::from_linear(&self.to_rotation_matrix())
+    }
+
+    //intrinsic rotations: the first angle rotates around the first axis of the order,
+    //the second around the resulting second axis, and so on
+    //(equivalent to extrinsic rotations around the axes in reverse order)
+    #[must_use]
+    pub fn from_euler(order: EulerOrder, angles: [f64; 3]) -> Self {
+        let sequence = order.axes();
+        let rotations = [0, 1, 2].map(|index| {
+            let mut axis = [0.0; 3];
+            axis[sequence[index]] = 1.0;
+            Self::from_axis_angle(Vector::from_array(axis), angles[index])
+        });
+
+        rotations[0] * rotations[1] * rotations[2]
+    }
+
+    //inverse of from_euler, with the first and third angles in [-pi, pi] and the second in
+    //[-pi/2, pi/2] for Tait-Bryan orders or [0, pi] for proper euler orders.
+    //in gimbal lock only the combined rotation is determined, so the first angle is set to 0
+    #[must_use]
+    pub fn to_euler(&self, order: EulerOrder) -> [f64; 3] {
+        //direct conversion as described by Bernardes and Viollet (2022), computing the
+        //extrinsic angles for the reversed axis sequence and then reversing them
+        let [third, second, first] = order.axes();
+        let unit = self.normalize().unwrap_or(Self::identity());
+        let components = [unit.x, unit.y, unit.z];
+
+        let proper = first == third;
+        let third = if proper { 3 - first - second } else { third };
+        //levi-civita symbol of the three distinct axes
+        #[allow(clippy::cast_possible_wrap, clippy::cast_precision_loss)]
+        let sign = ((first as isize - second as isize)
+            * (second as isize - third as isize)
+            * (third as isize - first as isize)
+            / 2) as f64;
+
+        let (a, b, c, d) = if proper {
+            (
+                unit.w,
+                components[first],
+                components[second],
+                components[third] * sign,
+            )
+        } else {
+            (
+                unit.w - components[second],
+                components[first] + components[third] * sign,
+                components[second] + unit.w,
+                components[third] * sign - components[first],
+            )
+        };
+
+        let mut angles = [0.0; 3];
+        angles[1] = 2.0 * libm::atan2(libm::hypot(c, d), libm::hypot(a, b));
+
+        let half_sum = libm::atan2(b, a);
+        let half_difference = libm::atan2(d, c);
+
+        if f64_abs(angles[1]) <= GIMBAL_LOCK_TOLERANCE {
+            angles[0] = 2.0 * half_sum;
+        } else if f64_abs(angles[1] - PI) <= GIMBAL_LOCK_TOLERANCE {
+            angles[0] = -2.0 * half_difference;
+        } else {
+            angles[0] = half_sum - half_difference;
+            angles[2] = half_sum + half_difference;
+        }
+
+        if !proper {
+            angles[2] *= sign;
+            angles[1] -= PI / 2.0;
+        }
+
+        angles.reverse();
+        angles.map(|angle| {
+            //wrap into [-pi, pi]
+            if angle > PI {
+                angle - 2.0 * PI
+            } else if angle < -PI {
+                angle + 2.0 * PI
+            } else {
+                angle
+            }
+        })
+    }
+
+    #[must_use]
+    pub fn conjugate(&self) -> Self {
+        Self::new(self.w, -self.x, -self.y, -self.z)
+    }
+
+    #[must_use]
+    pub fn dot(&self, rhs: &Self) -> f64 {
+        self.w * rhs.w + self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
+    }
+
+    #[must_use]
+    pub fn norm_squared(&self) -> f64 {
+        self.dot(self)
+    }
+
+    #[must_use]
+    pub fn norm(&self) -> f64 {
+        libm::sqrt(self.norm_squared())
+    }
+
+    //returns None for the zero quaternion
+    #[must_use]
+    pub fn normalize(&self) -> Option<Self> {
+        let norm = self.norm();
+        if norm == 0.0 {
+            None
+        } else {
+            Some(*self * (1.0 / norm))
+        }
+    }
+
+    //returns None for the zero quaternion
+    #[must_use]
+    pub fn inverse(&self) -> Option<Self> {
+        let norm_squared = self.norm_squared();
+        if norm_squared == 0.0 {
+            None
+        } else {
+            Some(self.conjugate() * (1.0 / norm_squared))
+        }
+    }
+
+    //rotates a vector by this (assumed unit) quaternion
+    #[must_use]
+    pub fn rotate_vector(&self, vector: Vector<3>) -> Vector<3> {
+        let [x, y, z] = vector.to_array();
+        let rotated = *self * Self::new(0.0, x, y, z) * self.conjugate();
+        Vector::from_array([rotated.x, rotated.y, rotated.z])
+    }
+
+    //normalized linear interpolation, always taking the shortest path
+    #[must_use]
+    pub fn nlerp(&self, other: &Self, t: f64) -> Self {
+        let other = if self.dot(other) < 0.0 {
+            -*other
+        } else {
+            *other
+        };
+        (*self * (1.0 - t) + other * t)
+            .normalize()
+            .unwrap_or(Self::identity())
+    }
+
+    //spherical linear interpolation between two unit quaternions, always taking the shortest path
+    #[must_use]
+    pub fn slerp(&self, other: &Self, t: f64) -> Self {
+        let mut other = *other;
+        let mut cos = self.dot(&other);
+        if cos < 0.0 {
+            other = -other;
+            cos = -cos;
+        }
+
+        //nearly parallel quaternions make sin(theta) vanish, where nlerp is accurate enough
+        if cos > 0.9995 {
+            return self.nlerp(&other, t);
+        }
+
+        let theta = libm::acos(cos);
+        let sin = libm::sin(theta);
+        *self * (libm::sin((1.0 - t) * theta) / sin) + other * (libm::sin(t * theta) / sin)
+    }
+}
@@ -0,0 +1,217 @@
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "alloc")]
+use crate::dmat::DMat;
+use crate::mat::Mat;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+//reductions of whole matrices, of each row (the *_rows functions, giving a column)
+//and of each column (the *_cols functions, giving a row)
+//
+//as in NumPy, NaN values propagate: reducing values that include NaN gives NaN,
+//and argmin/argmax give the index of the first NaN
+//the nan_* variants skip NaN values instead
+//the min, max and mean of no values are NaN, and their argmin and argmax are None
+
+fn sum<I: Iterator<Item = f64>>(values: I) -> f64 {
+    values.fold(0.0, |acc, n| acc + n)
+}
+
+fn product<I: Iterator<Item = f64>>(values: I) -> f64 {
+    values.fold(1.0, |acc, n| acc * n)
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn mean<I: Iterator<Item = f64>>(values: I) -> f64 {
+    let (sum, count) = values.fold((0.0, 0_usize), |(sum, count), n| (sum + n, count + 1));
+    sum / count as f64
+}
+
+//the first (index, value) pair no other value is better than, or the first NaN
+fn extremum<I: Iterator<Item = (usize, f64)>>(
+    values: I,
+    better: fn(f64, f64) -> bool,
+) -> Option<(usize, f64)> {
+    let mut best: Option<(usize, f64)> = None;
+    for (index, n) in values {
+        if n.is_nan() {
+            return Some((index, n));
+        }
+        if best.is_none_or(|(_, best)| better(n, best)) {
+            best = Some((index, n));
+        }
+    }
+    best
+}
+
+fn less(a: f64, b: f64) -> bool {
+    a < b
+}
+
+fn greater(a: f64, b: f64) -> bool {
+    a > b
+}
+
+fn min<I: Iterator<Item = f64>>(values: I) -> f64 {
+    extremum(values.enumerate(), less).map_or(f64::NAN, |(_, n)| n)
+}
+
+fn max<I: Iterator<Item = f64>>(values: I) -> f64 {
+    extremum(values.enumerate(), greater).map_or(f64::NAN, |(_, n)| n)
+}
+
+fn argmin<I: Iterator<Item = f64>>(values: I) -> Option<usize> {
+    extremum(values.enumerate(), less).map(|(index, _)| index)
+}
+
+fn argmax<I: Iterator<Item = f64>>(values: I) -> Option<usize> {
+    extremum(values.enumerate(), greater).map(|(index, _)| index)
+}
+
+fn nan_sum<I: Iterator<Item = f64>>(values: I) -> f64 {
+    sum(values.filter(|n| !n.is_nan()))
+}
+
+fn nan_product<I: Iterator<Item = f64>>(values: I) -> f64 {
+    product(values.filter(|n| !n.is_nan()))
+}
+
+fn nan_mean<I: Iterator<Item = f64>>(values: I) -> f64 {
+    mean(values.filter(|n| !n.is_nan()))
+}
+
+fn nan_min<I: Iterator<Item = f64>>(values: I) -> f64 {
+    min(values.filter(|n| !n.is_nan()))
+}
+
+fn nan_max<I: Iterator<Item = f64>>(values: I) -> f64 {
+    max(values.filter(|n| !n.is_nan()))
+}
+
+//NaN values are skipped after enumerating, to keep the indices of the others
+fn nan_argmin<I: Iterator<Item = f64>>(values: I) -> Option<usize> {
+    let values = values.enumerate().filter(|(_, n)| !n.is_nan());
+    extremum(values, less).map(|(index, _)| index)
+}
+
+fn nan_argmax<I: Iterator<Item = f64>>(values: I) -> Option<usize> {
+    let values = values.enumerate().filter(|(_, n)| !n.is_nan());
+    extremum(values, greater).map(|(index, _)| index)
+}
+
+//implements the whole matrix, per row and per column functions of each reduction to a value
+macro_rules! value_reductions {
+    ($( $whole: ident, $rows: ident, $cols: ident => $reduce: ident; )*) => {
+        impl<const R: usize, const C: usize> Mat<R, C> {
+            $(
+                #[must_use]
+                pub fn $whole(&self) -> f64 {
+                    $reduce(self.iter().flatten().copied())
+                }
+
+                #[must_use]
+                pub fn $rows(&self) -> Mat<R, 1> {
+                    Mat::generate(|row, _| $reduce(self[row].iter().copied()))
+                }
+
+                #[must_use]
+                pub fn $cols(&self) -> Mat<1, C> {
+                    Mat::generate(|_, col| $reduce(self.iter().map(|row| row[col])))
+                }
+            )*
+        }
+
+        #[cfg(feature = "alloc")]
+        impl DMat {
+            $(
+                #[must_use]
+                pub fn $whole(&self) -> f64 {
+                    $reduce(self.as_slice().iter().copied())
+                }
+
+                #[must_use]
+                pub fn $rows(&self) -> DMat {
+                    DMat::generate(self.rows(), 1, |row, _| $reduce(self[row].iter().copied()))
+                }
+
+                #[must_use]
+                pub fn $cols(&self) -> DMat {
+                    DMat::generate(1, self.cols(), |_, col| {
+                        $reduce(self.row_iter().map(|row| row[col]))
+                    })
+                }
+            )*
+        }
+    };
+}
+
+//implements the whole matrix, per row and per column functions of each reduction to an index,
+//whole matrix indices being (row, col) pairs
+macro_rules! index_reductions {
+    ($( $whole: ident, $rows: ident, $cols: ident => $reduce: ident; )*) => {
+        impl<const R: usize, const C: usize> Mat<R, C> {
+            $(
+                #[must_use]
+                pub fn $whole(&self) -> Option<(usize, usize)> {
+                    $reduce(self.iter().flatten().copied()).map(|index| (index / C, index % C))
+                }
+
+                #[must_use]
+                pub fn $rows(&self) -> [Option<usize>; R] {
+                    core::array::from_fn(|row| $reduce(self[row].iter().copied()))
+                }
+
+                #[must_use]
+                pub fn $cols(&self) -> [Option<usize>; C] {
+                    core::array::from_fn(|col| $reduce(self.iter().map(|row| row[col])))
+                }
+            )*
+        }
+
+        #[cfg(feature = "alloc")]
+        impl DMat {
+            $(
+                #[must_use]
+                pub fn $whole(&self) -> Option<(usize, usize)> {
+                    $reduce(self.as_slice().iter().copied())
+                        .map(|index| (index / self.cols(), index % self.cols()))
+                }
+
+                #[must_use]
+                pub fn $rows(&self) -> Vec<Option<usize>> {
+                    self.row_iter()
+                        .map(|row| $reduce(row.iter().copied()))
+                        .collect()
+                }
+
+                #[must_use]
+                pub fn $cols(&self) -> Vec<Option<usize>> {
+                    (0..self.cols())
+                        .map(|col| $reduce(self.row_iter().map(|row| row[col])))
+                        .collect()
+                }
+            )*
+        }
+    };
+}
+
+value_reductions! {
+    sum, sum_rows, sum_cols => sum;
+    product, product_rows, product_cols => product;
+    mean, mean_rows, mean_cols => mean;
+    min, min_rows, min_cols => min;
+    max, max_rows, max_cols => max;
+    nan_sum, nan_sum_rows, nan_sum_cols => nan_sum;
+    nan_product, nan_product_rows, nan_product_cols => nan_product;
+    nan_mean, nan_mean_rows, nan_mean_cols => nan_mean;
+    nan_min, nan_min_rows, nan_min_cols => nan_min;
+    nan_max, nan_max_rows, nan_max_cols => nan_max;
+}
+
+index_reductions! {
+    argmin, argmin_rows, argmin_cols => argmin;
+    argmax, argmax_rows, argmax_cols => argmax;
+    nan_argmin, nan_argmin_rows, nan_argmin_cols => nan_argmin;
+    nan_argmax, nan_argmax_rows, nan_argmax_cols => nan_argmax;
+}
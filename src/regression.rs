@@ -0,0 +1,375 @@
+extern crate alloc;
+use crate::dmat::DMat;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Deref;
+
+//least squares linear models of targets (columns) from features (columns), over observations (rows)
+//
+//coefficients minimize sum(w * (y - X b - intercept)^2) + alpha * |b|^2 for each target column,
+//with alpha = 0 for LinearRegression, unit weights unless given and an intercept of 0 unless fitted
+//the intercept is never penalized: features and targets are centered on their weighted means
+//before solving, and the intercept recovered from the means afterwards
+//
+//the centered problem is solved by a Householder QR factorization of sqrt(W) X, stacked over
+//sqrt(alpha) I for ridge regression, without forming the normal equations, whose condition number
+//is the square of the features'. alpha must not be negative. the fit is rejected when a diagonal value of R is within rounding
+//error of zero, as then a feature is (numerically) a linear combination of the previous ones
+//
+//weights are inverse variances (up to a common factor) of the observations, as in weighted least squares
+//standard errors estimate the residual variance with n - p (- 1 with an intercept) degrees of freedom,
+//for n observations and p features (NaN when there are none left), and use the sandwich covariance
+//(G + alpha I)^-1 G (G + alpha I)^-1 of the coefficients, G being the weighted Gram matrix of the
+//centered features, treating alpha as fixed
+//R² is relative to the weighted variance of each target around its mean, even without an intercept,
+//and NaN for a target without variance, as any model (or none) explains all of it
+
+//parameters of a fitted model, shared by LinearRegression and RidgeRegression
+#[derive(Debug, PartialEq, Clone)]
+pub struct LinearModel {
+    //features x targets
+    coefficients: DMat,
+    //1 x targets
+    intercept: DMat,
+    standard_errors: DMat,
+    intercept_standard_errors: DMat,
+    r_squared: Vec<f64>,
+}
+
+//reduces a (rows x cols, rows >= cols) to R in its upper rows by Householder reflections, applying
+//them to b, so that a b = c becomes R x = the upper rows of b
+//returns false when a diagonal value of R is not above the tolerance times the norm of its column
+//of a (or is NaN), the column then being a linear combination of the previous ones
+#[allow(clippy::needless_range_loop)]
+fn householder(a: &mut DMat, b: &mut DMat, tolerance: f64) -> bool {
+    let (rows, cols) = (a.rows(), a.cols());
+    if rows < cols {
+        return false;
+    }
+
+    let column_norms: Vec<f64> = (0..cols)
+        .map(|col| libm::sqrt(a.row_iter().fold(0.0, |acc, row| acc + row[col] * row[col])))
+        .collect();
+    let mut reflector = vec![0.0; rows];
+    let (mut dots, mut b_dots) = (vec![0.0; cols], vec![0.0; b.cols()]);
+
+    for col in 0..cols {
+        let norm = libm::sqrt((col..rows).fold(0.0, |acc, row| acc + a[row][col] * a[row][col]));
+        if norm.is_nan() || norm <= tolerance * column_norms[col] {
+            return false;
+        }
+
+        //reflects the column onto -sign(a[col][col]) norm e_col, the sign avoiding cancellation
+        let diagonal = if a[col][col] > 0.0 { -norm } else { norm };
+        for row in col..rows {
+            reflector[row] = a[row][col];
+        }
+        reflector[col] -= diagonal;
+        let scale = 2.0 / (col..rows).fold(0.0, |acc, row| acc + reflector[row] * reflector[row]);
+
+        //x -= scale (v . x) v for the remaining columns of a and every column of b
+        dots.fill(0.0);
+        b_dots.fill(0.0);
+        for row in col..rows {
+            for (dot, value) in dots[col + 1..].iter_mut().zip(&a[row][col + 1..]) {
+                *dot += reflector[row] * value;
+            }
+            for (dot, value) in b_dots.iter_mut().zip(&b[row]) {
+                *dot += reflector[row] * value;
+            }
+        }
+        for row in col..rows {
+            let factor = scale * reflector[row];
+            for (value, dot) in a[row][col + 1..].iter_mut().zip(&dots[col + 1..]) {
+                *value -= factor * dot;
+            }
+            for (value, dot) in b[row].iter_mut().zip(&b_dots) {
+                *value -= factor * dot;
+            }
+            a[row][col] = 0.0;
+        }
+        a[col][col] = diagonal;
+    }
+    true
+}
+
+//solves R x = b for every column of b by back substitution, R being the upper triangle of a's
+//first a.cols() rows, and b's rows below those being ignored
+fn back_substitution(a: &DMat, b: &DMat) -> DMat {
+    let size = a.cols();
+    let mut solution = DMat::zero(size, b.cols());
+    for row in (0..size).rev() {
+        for col in 0..b.cols() {
+            let dot =
+                (row + 1..size).fold(b[row][col], |acc, k| acc - a[row][k] * solution[k][col]);
+            solution[row][col] = dot / a[row][row];
+        }
+    }
+    solution
+}
+
+//mat over rows more rows holding diagonal on their diagonal, and 0 elsewhere
+fn stack_diagonal(mat: &DMat, rows: usize, diagonal: f64) -> DMat {
+    DMat::generate(mat.rows() + rows, mat.cols(), |row, col| {
+        match row.checked_sub(mat.rows()) {
+            None => mat[row][col],
+            Some(index) if index == col => diagonal,
+            Some(_) => 0.0,
+        }
+    })
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn fit(
+    features: &DMat,
+    targets: &DMat,
+    weights: &[f64],
+    alpha: f64,
+    intercept: bool,
+) -> Option<LinearModel> {
+    assert!(
+        features.rows() == targets.rows(),
+        "Attempted to fit features and targets with different numbers of observations"
+    );
+    assert!(
+        alpha >= 0.0,
+        "Attempted to fit a ridge regression with a negative penalty"
+    );
+
+    let weight_sum: f64 = weights.iter().sum();
+
+    let (feature_means, target_means) = if intercept {
+        (
+            features.weighted_mean(weights),
+            targets.weighted_mean(weights),
+        )
+    } else {
+        (
+            DMat::zero(1, features.cols()),
+            DMat::zero(1, targets.cols()),
+        )
+    };
+    let centered_features = features.broadcast_sub(&feature_means);
+    let centered_targets = targets.broadcast_sub(&target_means);
+
+    //sqrt(W) X and sqrt(W) Y, stacked over sqrt(alpha) I and zeros for ridge regression,
+    //whose least squares solution minimizes the penalized sum of squares
+    let penalty_rows = if alpha > 0.0 { features.cols() } else { 0 };
+    let scale = |mat: &DMat| {
+        DMat::generate(mat.rows(), mat.cols(), |row, col| {
+            libm::sqrt(weights[row]) * mat[row][col]
+        })
+    };
+    let scaled_features = scale(&centered_features);
+    let mut reduced = stack_diagonal(&scaled_features, penalty_rows, libm::sqrt(alpha));
+    let mut reduced_targets = stack_diagonal(&scale(&centered_targets), penalty_rows, 0.0);
+    //the diagonal values of R of linearly dependent features are rounding errors below
+    //(n + p) epsilon times the norms of their columns, 4 times that leaves a margin
+    let tolerance = 4.0 * (features.rows() + features.cols()) as f64 * f64::EPSILON;
+    if !householder(&mut reduced, &mut reduced_targets, tolerance) {
+        return None;
+    }
+
+    let coefficients = back_substitution(&reduced, &reduced_targets);
+    let intercept_values = &target_means - &(&feature_means * &coefficients);
+
+    let residuals = (targets - &(features * &coefficients)).broadcast_sub(&intercept_values);
+    let weighted_square_sum = |mat: &DMat| {
+        DMat::generate(1, mat.cols(), |_, col| {
+            mat.row_iter()
+                .zip(weights)
+                .fold(0.0, |acc, (row, weight)| acc + weight * row[col] * row[col])
+        })
+    };
+    let residual_square_sums = weighted_square_sum(&residuals);
+    let total_square_sums = weighted_square_sum(&targets.weighted_center(weights));
+    let r_squared = residual_square_sums
+        .as_slice()
+        .iter()
+        .zip(total_square_sums.as_slice())
+        .map(|(residual, &total)| {
+            if total > 0.0 {
+                1.0 - residual / total
+            } else {
+                f64::NAN
+            }
+        })
+        .collect();
+
+    let degrees_of_freedom =
+        features.rows() as f64 - features.cols() as f64 - f64::from(u8::from(intercept));
+    //without degrees of freedom left, the residual variance cannot be estimated
+    let residual_variances = if degrees_of_freedom > 0.0 {
+        residual_square_sums / degrees_of_freedom
+    } else {
+        DMat::generate(1, targets.cols(), |_, _| f64::NAN)
+    };
+
+    //the inverse is only formed for the covariance: (R^T R)^-1 = (G + alpha I)^-1,
+    //and the sandwich covariance is K^T K with K = sqrt(W) X (R^T R)^-1
+    let r_inverse = back_substitution(&reduced, &DMat::identity(features.cols()));
+    let regularized_inverse = &r_inverse * &r_inverse.transpose();
+    let covariance = if alpha > 0.0 {
+        let product = &scaled_features * &regularized_inverse;
+        product.transpose() * &product
+    } else {
+        regularized_inverse
+    };
+    let standard_errors = DMat::generate(coefficients.rows(), coefficients.cols(), |row, col| {
+        libm::sqrt(residual_variances[0][col] * covariance[row][row])
+    });
+
+    //the intercept is the target mean minus the feature means times the coefficients,
+    //and the mean of the centered targets is uncorrelated with the coefficients
+    let intercept_standard_errors = if intercept {
+        let mean_variance = (&feature_means * &covariance * feature_means.transpose())[0][0];
+        residual_variances.map(|variance| libm::sqrt(variance * (1.0 / weight_sum + mean_variance)))
+    } else {
+        DMat::zero(1, targets.cols())
+    };
+
+    Some(LinearModel {
+        coefficients,
+        intercept: intercept_values,
+        standard_errors,
+        intercept_standard_errors,
+        r_squared,
+    })
+}
+
+fn assert_weights(features: &DMat, weights: &[f64]) {
+    assert!(
+        weights.len() == features.rows(),
+        "Attempted to weight observations with the wrong number of weights"
+    );
+}
+
+impl LinearModel {
+    //features x targets matrix of coefficients
+    #[must_use]
+    pub fn coefficients(&self) -> &DMat {
+        &self.coefficients
+    }
+
+    //intercept of each target, 0 if it was not fitted
+    #[must_use]
+    pub fn intercept(&self) -> &[f64] {
+        self.intercept.as_slice()
+    }
+
+    //features x targets matrix of the standard errors of the coefficients,
+    //NaN without degrees of freedom left
+    #[must_use]
+    pub fn standard_errors(&self) -> &DMat {
+        &self.standard_errors
+    }
+
+    //standard error of the intercept of each target, 0 if it was not fitted,
+    //NaN without degrees of freedom left
+    #[must_use]
+    pub fn intercept_standard_errors(&self) -> &[f64] {
+        self.intercept_standard_errors.as_slice()
+    }
+
+    //coefficient of determination of each target on the observations the model was fitted to
+    #[must_use]
+    pub fn r_squared(&self) -> &[f64] {
+        &self.r_squared
+    }
+
+    //observations x targets matrix of the predicted targets of the given observations
+    #[must_use]
+    pub fn predict(&self, features: &DMat) -> DMat {
+        assert!(
+            features.cols() == self.coefficients.rows(),
+            "Attempted to predict from observations with the wrong number of features"
+        );
+        (features * &self.coefficients).broadcast_add(&self.intercept)
+    }
+}
+
+//ordinary (or weighted) least squares
+#[derive(Debug, PartialEq, Clone)]
+pub struct LinearRegression(LinearModel);
+
+impl Deref for LinearRegression {
+    type Target = LinearModel;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl LinearRegression {
+    //returns None if the features are linearly dependent (up to rounding errors), as with fewer
+    //observations than features, their coefficients then not being unique
+    #[must_use]
+    pub fn fit(features: &DMat, targets: &DMat, intercept: bool) -> Option<Self> {
+        fit(
+            features,
+            targets,
+            &vec![1.0; features.rows()],
+            0.0,
+            intercept,
+        )
+        .map(Self)
+    }
+
+    #[must_use]
+    pub fn fit_weighted(
+        features: &DMat,
+        targets: &DMat,
+        weights: &[f64],
+        intercept: bool,
+    ) -> Option<Self> {
+        assert_weights(features, weights);
+        fit(features, targets, weights, 0.0, intercept).map(Self)
+    }
+}
+
+//least squares with an L2 penalty of alpha times the squared norm of the coefficients
+#[derive(Debug, PartialEq, Clone)]
+pub struct RidgeRegression {
+    model: LinearModel,
+    alpha: f64,
+}
+
+impl Deref for RidgeRegression {
+    type Target = LinearModel;
+    fn deref(&self) -> &Self::Target {
+        &self.model
+    }
+}
+
+impl RidgeRegression {
+    //returns None if the features are linearly dependent (up to rounding errors) and alpha is 0 or too
+    //small relative to them to make the coefficients unique
+    #[must_use]
+    pub fn fit(features: &DMat, targets: &DMat, alpha: f64, intercept: bool) -> Option<Self> {
+        let model = fit(
+            features,
+            targets,
+            &vec![1.0; features.rows()],
+            alpha,
+            intercept,
+        )?;
+        Some(Self { model, alpha })
+    }
+
+    #[must_use]
+    pub fn fit_weighted(
+        features: &DMat,
+        targets: &DMat,
+        weights: &[f64],
+        alpha: f64,
+        intercept: bool,
+    ) -> Option<Self> {
+        assert_weights(features, weights);
+        let model = fit(features, targets, weights, alpha, intercept)?;
+        Some(Self { model, alpha })
+    }
+
+    #[must_use]
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+}
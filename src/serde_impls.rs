@@ -0,0 +1,212 @@
+extern crate alloc;
+use crate::dmat::DMat;
+use crate::mat::Mat;
+use alloc::vec::Vec;
+use core::fmt;
+use serde::de::{self, DeserializeSeed, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::ser::{SerializeSeq, SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+
+//Mat<R, C> is (de)serialized as a nested array of R rows holding C values each
+
+struct Row<'a>(&'a [f64]);
+
+impl Serialize for Row<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+        for n in self.0 {
+            seq.serialize_element(n)?;
+        }
+        seq.end()
+    }
+}
+
+impl<const R: usize, const C: usize> Serialize for Mat<R, C> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(R))?;
+        for row in self.iter() {
+            seq.serialize_element(&Row(row))?;
+        }
+        seq.end()
+    }
+}
+
+//deserializes a row of exactly C values, rejecting rows of any other length
+struct RowVisitor<const C: usize>;
+
+impl<'de, const C: usize> Visitor<'de> for RowVisitor<C> {
+    type Value = [f64; C];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a row of {C} numbers")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut row = [0.0; C];
+        for (index, n) in row.iter_mut().enumerate() {
+            *n = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(index, &self))?;
+        }
+
+        if seq.next_element::<de::IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_length(C + 1, &self));
+        }
+
+        Ok(row)
+    }
+}
+
+impl<'de, const C: usize> DeserializeSeed<'de> for RowVisitor<C> {
+    type Value = [f64; C];
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+//deserializes exactly R rows, rejecting matrices with any other number of rows
+struct MatVisitor<const R: usize, const C: usize>;
+
+impl<'de, const R: usize, const C: usize> Visitor<'de> for MatVisitor<R, C> {
+    type Value = Mat<R, C>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "an array of {R} rows of {C} numbers")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut mat = Mat::zero();
+        for (index, row) in mat.iter_mut().enumerate() {
+            *row = seq
+                .next_element_seed(RowVisitor::<C>)?
+                .ok_or_else(|| de::Error::invalid_length(index, &self))?;
+        }
+
+        if seq.next_element::<de::IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_length(R + 1, &self));
+        }
+
+        Ok(mat)
+    }
+}
+
+impl<'de, const R: usize, const C: usize> Deserialize<'de> for Mat<R, C> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(MatVisitor::<R, C>)
+    }
+}
+
+//DMat is (de)serialized as a struct of its dimensions and its values in row-major order
+
+impl Serialize for DMat {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("DMat", 3)?;
+        state.serialize_field("rows", &self.rows())?;
+        state.serialize_field("cols", &self.cols())?;
+        state.serialize_field("data", self.as_slice())?;
+        state.end()
+    }
+}
+
+const DMAT_FIELDS: &[&str] = &["rows", "cols", "data"];
+
+enum DMatField {
+    Rows,
+    Cols,
+    Data,
+}
+
+struct DMatFieldVisitor;
+
+impl Visitor<'_> for DMatFieldVisitor {
+    type Value = DMatField;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("`rows`, `cols` or `data`")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        match value {
+            "rows" => Ok(DMatField::Rows),
+            "cols" => Ok(DMatField::Cols),
+            "data" => Ok(DMatField::Data),
+            _ => Err(de::Error::unknown_field(value, DMAT_FIELDS)),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for DMatField {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_identifier(DMatFieldVisitor)
+    }
+}
+
+struct DMatVisitor;
+
+impl DMatVisitor {
+    fn build<E: de::Error>(rows: usize, cols: usize, data: Vec<f64>) -> Result<DMat, E> {
+        match rows.checked_mul(cols) {
+            Some(len) if len == data.len() => Ok(DMat::from_vec(rows, cols, data)),
+            Some(len) => Err(E::invalid_length(
+                data.len(),
+                &alloc::format!("{len} values for a {rows}x{cols} matrix").as_str(),
+            )),
+            None => Err(E::custom("matrix dimensions overflow")),
+        }
+    }
+}
+
+impl<'de> Visitor<'de> for DMatVisitor {
+    type Value = DMat;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("struct DMat")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let rows = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let cols = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        let data = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+        Self::build(rows, cols, data)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let (mut rows, mut cols, mut data) = (None, None, None);
+
+        while let Some(key) = map.next_key()? {
+            match key {
+                DMatField::Rows if rows.is_some() => {
+                    return Err(de::Error::duplicate_field("rows"))
+                }
+                DMatField::Cols if cols.is_some() => {
+                    return Err(de::Error::duplicate_field("cols"))
+                }
+                DMatField::Data if data.is_some() => {
+                    return Err(de::Error::duplicate_field("data"))
+                }
+                DMatField::Rows => rows = Some(map.next_value()?),
+                DMatField::Cols => cols = Some(map.next_value()?),
+                DMatField::Data => data = Some(map.next_value()?),
+            }
+        }
+
+        Self::build(
+            rows.ok_or_else(|| de::Error::missing_field("rows"))?,
+            cols.ok_or_else(|| de::Error::missing_field("cols"))?,
+            data.ok_or_else(|| de::Error::missing_field("data"))?,
+        )
+    }
+}
+
+impl<'de> Deserialize<'de> for DMat {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_struct("DMat", DMAT_FIELDS, DMatVisitor)
+    }
+}
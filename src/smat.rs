@@ -0,0 +1,545 @@
+use crate::f64_abs;
+use crate::gemm::gemm_slices;
+use crate::mat::Mat;
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
+
+//dynamically sized matrix stored inline, holding up to CAP values, for targets without a heap
+//
+//values are kept in row-major order at the start of the array, the rest of which is unused
+//and kept zeroed, so the size of an SMat is always CAP values whatever its dimensions
+#[derive(Debug, Clone, Copy)]
+pub struct SMat<const CAP: usize> {
+    vals: [f64; CAP],
+    rows: usize,
+    cols: usize,
+}
+
+impl<const CAP: usize> PartialEq for SMat<CAP> {
+    fn eq(&self, other: &Self) -> bool {
+        if self.rows != other.rows || self.cols != other.cols {
+            false
+        } else {
+            self.as_slice() == other.as_slice()
+        }
+    }
+}
+
+impl<const CAP: usize> Index<usize> for SMat<CAP> {
+    type Output = [f64];
+    fn index(&self, index: usize) -> &Self::Output {
+        let starting_idx = self.cols * index;
+        &self.as_slice()[starting_idx..(starting_idx + self.cols)]
+    }
+}
+
+impl<const CAP: usize> IndexMut<usize> for SMat<CAP> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let (starting_idx, cols) = (self.cols * index, self.cols);
+        &mut self.as_mut_slice()[starting_idx..(starting_idx + cols)]
+    }
+}
+
+impl<const CAP: usize> SMat<CAP> {
+    pub fn row_iter(&self) -> impl Iterator<Item = &[f64]> {
+        (0..self.rows).map(|row| &self[row])
+    }
+
+    #[must_use]
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    #[must_use]
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    //maximum number of values the matrix can hold
+    #[must_use]
+    pub const fn capacity() -> usize {
+        CAP
+    }
+
+    //values in row-major order
+    #[must_use]
+    pub fn as_slice(&self) -> &[f64] {
+        &self.vals[..self.rows * self.cols]
+    }
+
+    #[must_use]
+    pub fn as_mut_slice(&mut self) -> &mut [f64] {
+        &mut self.vals[..self.rows * self.cols]
+    }
+}
+
+impl<const CAP: usize> Add<&Self> for SMat<CAP> {
+    type Output = Self;
+    fn add(mut self, rhs: &Self) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl<const CAP: usize> Add<Self> for SMat<CAP> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Add::<&Self>::add(self, &rhs)
+    }
+}
+
+impl<const CAP: usize> Add for &SMat<CAP> {
+    type Output = SMat<CAP>;
+    fn add(self, rhs: Self) -> Self::Output {
+        *self + rhs
+    }
+}
+
+impl<const CAP: usize> AddAssign<&Self> for SMat<CAP> {
+    fn add_assign(&mut self, rhs: &Self) {
+        assert!(
+            (self.rows, self.cols) == (rhs.rows, rhs.cols),
+            "Attempted to add two matrices of different sizes"
+        );
+        for (val, rhs) in self.as_mut_slice().iter_mut().zip(rhs.as_slice()) {
+            *val += rhs;
+        }
+    }
+}
+
+impl<const CAP: usize> AddAssign for SMat<CAP> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self += &rhs;
+    }
+}
+
+impl<const CAP: usize> Sub<&Self> for SMat<CAP> {
+    type Output = Self;
+    fn sub(mut self, rhs: &Self) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl<const CAP: usize> Sub<Self> for SMat<CAP> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Sub::<&Self>::sub(self, &rhs)
+    }
+}
+
+impl<const CAP: usize> Sub for &SMat<CAP> {
+    type Output = SMat<CAP>;
+    fn sub(self, rhs: Self) -> Self::Output {
+        *self - rhs
+    }
+}
+
+impl<const CAP: usize> SubAssign<&Self> for SMat<CAP> {
+    fn sub_assign(&mut self, rhs: &Self) {
+        assert!(
+            (self.rows, self.cols) == (rhs.rows, rhs.cols),
+            "Attempted to subtract two matrices of different sizes"
+        );
+        for (val, rhs) in self.as_mut_slice().iter_mut().zip(rhs.as_slice()) {
+            *val -= rhs;
+        }
+    }
+}
+
+impl<const CAP: usize> SubAssign for SMat<CAP> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self -= &rhs;
+    }
+}
+
+impl<const CAP: usize, T: Into<f64>> Mul<T> for SMat<CAP> {
+    type Output = Self;
+    fn mul(mut self, scalar: T) -> Self::Output {
+        self *= scalar;
+        self
+    }
+}
+
+impl<const CAP: usize, T: Into<f64>> Mul<T> for &SMat<CAP> {
+    type Output = SMat<CAP>;
+    fn mul(self, scalar: T) -> Self::Output {
+        *self * scalar
+    }
+}
+
+impl<const CAP: usize, T: Into<f64>> MulAssign<T> for SMat<CAP> {
+    fn mul_assign(&mut self, scalar: T) {
+        let scalar: f64 = scalar.into();
+        self.mutate(|val, _, _| val * scalar);
+    }
+}
+
+impl<const CAP: usize> Mul<Self> for SMat<CAP> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        <&SMat<CAP> as Mul>::mul(&self, &rhs)
+    }
+}
+
+impl<const CAP: usize> Mul<&Self> for SMat<CAP> {
+    type Output = Self;
+    fn mul(self, rhs: &Self) -> Self::Output {
+        <&SMat<CAP> as Mul>::mul(&self, rhs)
+    }
+}
+
+impl<const CAP: usize> Mul for &SMat<CAP> {
+    type Output = SMat<CAP>;
+    fn mul(self, rhs: Self) -> Self::Output {
+        let mut product = SMat::zero(0, 0);
+        SMat::mul_into(self, rhs, &mut product);
+        product
+    }
+}
+
+impl<const CAP: usize> MulAssign<&Self> for SMat<CAP> {
+    fn mul_assign(&mut self, rhs: &Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const CAP: usize> MulAssign for SMat<CAP> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self *= &rhs;
+    }
+}
+
+impl<const CAP: usize> Neg for SMat<CAP> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        self * -1
+    }
+}
+
+impl<const CAP: usize> Neg for &SMat<CAP> {
+    type Output = SMat<CAP>;
+    fn neg(self) -> Self::Output {
+        self * -1
+    }
+}
+
+impl<const CAP: usize, T: Into<f64>> Div<T> for SMat<CAP> {
+    type Output = Self;
+    fn div(mut self, scalar: T) -> Self::Output {
+        self /= scalar;
+        self
+    }
+}
+
+impl<const CAP: usize, T: Into<f64>> Div<T> for &SMat<CAP> {
+    type Output = SMat<CAP>;
+    fn div(self, scalar: T) -> Self::Output {
+        *self / scalar
+    }
+}
+
+impl<const CAP: usize, T: Into<f64>> DivAssign<T> for SMat<CAP> {
+    fn div_assign(&mut self, scalar: T) {
+        let scalar: f64 = scalar.into();
+        self.mutate(|val, _, _| val / scalar);
+    }
+}
+
+impl<const CAP: usize, const R: usize, const C: usize, T: Into<f64>> From<[[T; C]; R]>
+    for SMat<CAP>
+{
+    fn from(value: [[T; C]; R]) -> Self {
+        let mut mat = Self::zero(R, C);
+        let values = value.into_iter().flat_map(|row| row.map(|n| n.into()));
+        for (val, n) in mat.as_mut_slice().iter_mut().zip(values) {
+            *val = n;
+        }
+        mat
+    }
+}
+
+impl<const CAP: usize, const R: usize, const C: usize> From<Mat<R, C>> for SMat<CAP> {
+    fn from(value: Mat<R, C>) -> Self {
+        Self::generate(R, C, |row, col| value[row][col])
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const CAP: usize> From<&SMat<CAP>> for crate::dmat::DMat {
+    fn from(value: &SMat<CAP>) -> Self {
+        Self::generate(value.rows, value.cols, |row, col| value[row][col])
+    }
+}
+
+impl<const CAP: usize> SMat<CAP> {
+    #[must_use]
+    pub fn zero(rows: usize, cols: usize) -> Self {
+        let mut zero = Self {
+            vals: [0.0; CAP],
+            rows: 0,
+            cols: 0,
+        };
+        zero.reshape(rows, cols);
+        zero
+    }
+
+    //builds a matrix from values in row-major order
+    #[must_use]
+    pub fn from_slice(rows: usize, cols: usize, vals: &[f64]) -> Self {
+        assert!(
+            rows.checked_mul(cols) == Some(vals.len()),
+            "Attempted to build a matrix from the wrong number of values"
+        );
+
+        let mut mat = Self::zero(rows, cols);
+        mat.as_mut_slice().copy_from_slice(vals);
+        mat
+    }
+
+    #[must_use]
+    pub fn generate<F: Fn(usize, usize) -> f64>(rows: usize, cols: usize, f: F) -> Self {
+        let mut mat = Self::zero(rows, cols);
+        mat.mutate(|_, row, col| f(row, col));
+        mat
+    }
+
+    pub fn mutate<F: Fn(f64, usize, usize) -> f64>(&mut self, f: F) {
+        let cols = self.cols;
+        for (index, val) in self.as_mut_slice().iter_mut().enumerate() {
+            *val = f(*val, index / cols, index % cols);
+        }
+    }
+
+    #[must_use]
+    pub fn identity(n: usize) -> Self {
+        Self::generate(n, n, |row, col| if row == col { 1.0 } else { 0.0 })
+    }
+
+    #[must_use]
+    pub fn map<F: Fn(f64) -> f64>(&self, f: F) -> Self {
+        let mut mat = *self;
+        mat.mutate(|val, _, _| f(val));
+        mat
+    }
+
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        let mut transpose = Self::zero(self.cols, self.rows);
+        Self::transpose_into(self, &mut transpose);
+        transpose
+    }
+
+    //variants of the operators writing into an existing matrix, as for DMat
+
+    fn reshape(&mut self, rows: usize, cols: usize) {
+        let len = rows.checked_mul(cols);
+        assert!(
+            len.is_some_and(|len| len <= CAP),
+            "Attempted to create a matrix larger than its capacity"
+        );
+        //values beyond the new size are cleared, so the unused part of the array stays zeroed
+        if let Some(len) = len {
+            let old_len = self.rows * self.cols;
+            if len < old_len {
+                self.vals[len..old_len].fill(0.0);
+            }
+        }
+        self.rows = rows;
+        self.cols = cols;
+    }
+
+    pub fn add_into(a: &Self, b: &Self, out: &mut Self) {
+        assert!(
+            (a.rows, a.cols) == (b.rows, b.cols),
+            "Attempted to add two matrices of different sizes"
+        );
+        out.reshape(a.rows, a.cols);
+        for ((out, a), b) in out.vals.iter_mut().zip(a.as_slice()).zip(b.as_slice()) {
+            *out = a + b;
+        }
+    }
+
+    pub fn sub_into(a: &Self, b: &Self, out: &mut Self) {
+        assert!(
+            (a.rows, a.cols) == (b.rows, b.cols),
+            "Attempted to subtract two matrices of different sizes"
+        );
+        out.reshape(a.rows, a.cols);
+        for ((out, a), b) in out.vals.iter_mut().zip(a.as_slice()).zip(b.as_slice()) {
+            *out = a - b;
+        }
+    }
+
+    pub fn mul_into(a: &Self, b: &Self, out: &mut Self) {
+        assert!(
+            a.cols == b.rows,
+            "Attempted to multiply two non-commutative matrices"
+        );
+        out.reshape(a.rows, b.cols);
+        Self::gemm(1.0, a, b, 0.0, out);
+    }
+
+    pub fn scale_into<T: Into<f64>>(a: &Self, scalar: T, out: &mut Self) {
+        let scalar: f64 = scalar.into();
+        out.reshape(a.rows, a.cols);
+        for (out, a) in out.vals.iter_mut().zip(a.as_slice()) {
+            *out = a * scalar;
+        }
+    }
+
+    pub fn transpose_into(a: &Self, out: &mut Self) {
+        out.reshape(a.cols, a.rows);
+        for (index, n) in out.as_mut_slice().iter_mut().enumerate() {
+            let (col, row) = (index / a.rows, index % a.rows);
+            *n = a[row][col];
+        }
+    }
+
+    //computes c = alpha * a * b + beta * c in place, as DMat::gemm
+    pub fn gemm(alpha: f64, a: &Self, b: &Self, beta: f64, c: &mut Self) {
+        assert!(
+            a.cols == b.rows,
+            "Attempted to multiply two non-commutative matrices"
+        );
+        assert!(
+            (c.rows, c.cols) == (a.rows, b.cols),
+            "Attempted to accumulate a matrix product into a matrix of the wrong size"
+        );
+
+        let dimensions = (a.rows, a.cols, b.cols);
+        gemm_slices(
+            alpha,
+            a.as_slice(),
+            b.as_slice(),
+            dimensions,
+            beta,
+            c.as_mut_slice(),
+        );
+    }
+
+    #[must_use]
+    pub fn is_diagonal(&self) -> bool {
+        //non-square matrices cannot be diagonal
+        if self.rows != self.cols {
+            return false;
+        }
+
+        for row_index in 0..self.rows {
+            for col_index in 0..self.cols {
+                if (row_index != col_index) && (self[row_index][col_index] != 0.0) {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    #[must_use]
+    pub fn is_scalar_identity_multiple(&self) -> bool {
+        //non-square matrices cannot be scalar identity multiples
+        if self.rows != self.cols {
+            return false;
+        }
+        *self == Self::identity(self.rows) * self[0][0]
+    }
+
+    #[must_use]
+    pub fn is_orthogonal(&self) -> bool {
+        //non-square matrices cannot be orthogonal
+        if self.rows != self.cols {
+            return false;
+        }
+
+        *self * self.transpose() == Self::identity(self.rows)
+    }
+
+    #[must_use]
+    pub fn is_symmetric(&self) -> bool {
+        //non-square matrices cannot be symmetric
+        if self.rows != self.cols {
+            return false;
+        }
+        *self == self.transpose()
+    }
+
+    #[must_use]
+    pub fn to_determinant(mut self) -> f64 {
+        //determinant is undefined for non-square matrices
+        assert!(
+            self.rows == self.cols,
+            "Attempted to take determinant of non-square matrix"
+        );
+
+        let size = self.rows;
+
+        //perform gaussian elimination and store the determinant transformation coefficient
+        let mut transformation_coefficient = 1.0;
+
+        for k in 0..size {
+            //find k-th pivot, the largest element by magnitude on or below the diagonal
+            let pivot = (k..size).fold(k, |acc, index| {
+                if f64_abs(self[index][k]) > f64_abs(self[acc][k]) {
+                    index
+                } else {
+                    acc
+                }
+            });
+
+            if self[pivot][k] == 0.0 {
+                //matrix is singular
+                return 0.0;
+            }
+
+            //swap rows, flip transformation coefficient
+            if k != pivot {
+                for col in 0..size {
+                    let temp = self[k][col];
+                    self[k][col] = self[pivot][col];
+                    self[pivot][col] = temp;
+                }
+
+                transformation_coefficient *= -1.0;
+            }
+
+            //for all rows below pivot
+            let (upper, lower) = self.vals[..size * size].split_at_mut((k + 1) * size);
+            let pivot_row = &upper[k * size..];
+            for row in lower.chunks_exact_mut(size) {
+                let c = -row[k] / pivot_row[k];
+                //for all remaining elements in current row
+                for (n, pivot) in row[k + 1..].iter_mut().zip(&pivot_row[k + 1..]) {
+                    *n += pivot * c;
+                }
+                //fill lower triangle with 0s
+                row[k] = 0.0;
+            }
+        }
+
+        //return product of elements in diagonal multiplied by the transformation coefficient
+        let diagonal_product = self
+            .row_iter()
+            .enumerate()
+            .fold(1.0, |acc, (index, row)| acc * row[index]);
+
+        diagonal_product * transformation_coefficient
+    }
+
+    #[must_use]
+    pub fn determinant(&self) -> f64 {
+        self.to_determinant()
+    }
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __smat_macro {
+    ( $( $($e: expr),* );* ) => {
+        SMat::from([ $([ $($e),* ]),* ])
+    };
+}
+
+#[doc(inline)]
+pub use __smat_macro as smat;
@@ -0,0 +1,264 @@
+extern crate alloc;
+use crate::dmat::DMat;
+use alloc::vec;
+use alloc::vec::Vec;
+
+//statistics of a matrix of observations (rows) of variables (columns)
+//
+//covariances are unbiased sample estimates, normalized by n - 1 for n observations
+//weights are reliability weights, as NumPy's aweights: they scale the importance of each observation,
+//and covariances are normalized by sum(w) - sum(w^2) / sum(w), which is n - 1 when all weights are 1
+//with too few observations (or a total weight of 0) the normalization is 0, giving NaN or infinite values
+
+fn assert_weights(mat: &DMat, weights: &[f64]) {
+    assert!(
+        weights.len() == mat.rows(),
+        "Attempted to weight observations with the wrong number of weights"
+    );
+}
+
+//n - 1 for n observations
+#[allow(clippy::cast_precision_loss)]
+fn sample_normalization(observations: usize) -> f64 {
+    observations as f64 - 1.0
+}
+
+//sum(w) - sum(w^2) / sum(w)
+fn weighted_normalization(weights: &[f64]) -> f64 {
+    let (sum, square_sum) = weights.iter().fold((0.0, 0.0), |(sum, square_sum), w| {
+        (sum + w, square_sum + w * w)
+    });
+    sum - square_sum / sum
+}
+
+//divides every value by the square root of the corresponding diagonal values of the covariance
+fn covariance_to_correlation(mut covariance: DMat) -> DMat {
+    let deviations: Vec<f64> = (0..covariance.rows())
+        .map(|index| libm::sqrt(covariance[index][index]))
+        .collect();
+    covariance.mutate(|val, row, col| val / (deviations[row] * deviations[col]));
+    covariance
+}
+
+impl DMat {
+    //the matrix with the mean of each column subtracted from it
+    #[must_use]
+    pub fn center(&self) -> DMat {
+        self.broadcast_sub(&self.mean_cols())
+    }
+
+    //the matrix with each column centered and divided by its sample standard deviation
+    //constant columns have a standard deviation of 0, and become NaN
+    #[must_use]
+    pub fn standardize(&self) -> DMat {
+        let centered = self.center();
+        let deviations = centered
+            .map(|n| n * n)
+            .sum_cols()
+            .map(|n| libm::sqrt(n / sample_normalization(self.rows())));
+        centered.elementwise_div(&deviations)
+    }
+
+    //the cols x cols matrix of sample covariances between the variables
+    #[must_use]
+    pub fn covariance(&self) -> DMat {
+        let centered = self.center();
+        centered.transpose() * &centered / sample_normalization(self.rows())
+    }
+
+    //the cols x cols matrix of Pearson correlation coefficients between the variables
+    #[must_use]
+    pub fn correlation(&self) -> DMat {
+        covariance_to_correlation(self.covariance())
+    }
+
+    //the 1 x cols row of weighted means of the variables
+    #[must_use]
+    pub fn weighted_mean(&self, weights: &[f64]) -> DMat {
+        assert_weights(self, weights);
+        let weight_sum: f64 = weights.iter().sum();
+        let mut mean = DMat::zero(1, self.cols());
+        for (row, &weight) in self.row_iter().zip(weights) {
+            for (mean, n) in mean[0].iter_mut().zip(row) {
+                *mean += weight * n;
+            }
+        }
+        mean / weight_sum
+    }
+
+    #[must_use]
+    pub fn weighted_center(&self, weights: &[f64]) -> DMat {
+        self.broadcast_sub(&self.weighted_mean(weights))
+    }
+
+    #[must_use]
+    pub fn weighted_standardize(&self, weights: &[f64]) -> DMat {
+        let centered = self.weighted_center(weights);
+        let normalization = weighted_normalization(weights);
+        let deviations = DMat::generate(1, self.cols(), |_, col| {
+            let sum = centered
+                .row_iter()
+                .zip(weights)
+                .fold(0.0, |acc, (row, weight)| acc + weight * row[col] * row[col]);
+            libm::sqrt(sum / normalization)
+        });
+        centered.elementwise_div(&deviations)
+    }
+
+    #[must_use]
+    pub fn weighted_covariance(&self, weights: &[f64]) -> DMat {
+        let centered = self.weighted_center(weights);
+        let mut weighted = centered.clone();
+        for (row, &weight) in weights.iter().enumerate() {
+            for n in &mut weighted[row] {
+                *n *= weight;
+            }
+        }
+        centered.transpose() * &weighted / weighted_normalization(weights)
+    }
+
+    #[must_use]
+    pub fn weighted_correlation(&self, weights: &[f64]) -> DMat {
+        covariance_to_correlation(self.weighted_covariance(weights))
+    }
+}
+
+//single pass accumulator of the means and covariances of observations fed to it one at a time,
+//using Welford's algorithm (West's, for weighted observations), which avoids the cancellation
+//of the naive sum of squares formula
+//accumulators of separate parts of the data can be combined with merge
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunningCovariance {
+    count: usize,
+    weight_sum: f64,
+    weight_square_sum: f64,
+    mean: Vec<f64>,
+    //sum of the weighted products of deviations from the mean
+    comoment: DMat,
+}
+
+impl RunningCovariance {
+    #[must_use]
+    pub fn new(variables: usize) -> Self {
+        Self {
+            count: 0,
+            weight_sum: 0.0,
+            weight_square_sum: 0.0,
+            mean: vec![0.0; variables],
+            comoment: DMat::zero(variables, variables),
+        }
+    }
+
+    pub fn push(&mut self, observation: &[f64]) {
+        self.push_weighted(observation, 1.0);
+    }
+
+    pub fn push_weighted(&mut self, observation: &[f64], weight: f64) {
+        assert!(
+            observation.len() == self.mean.len(),
+            "Attempted to add an observation with the wrong number of variables"
+        );
+
+        self.count += 1;
+        let previous_weight_sum = self.weight_sum;
+        self.weight_sum += weight;
+        self.weight_square_sum += weight * weight;
+        if self.weight_sum == 0.0 {
+            return;
+        }
+
+        //(x - old mean) (x - new mean)^T, scaled by the weight, is the outer product of the deviations
+        //from the previous mean scaled by weight * previous weight sum / weight sum
+        //the comoment is updated first, so the deviations can be computed from the mean in place
+        let scale = weight * previous_weight_sum / self.weight_sum;
+        let mean = &self.mean;
+        self.comoment.mutate(|val, row, col| {
+            val + scale * (observation[row] - mean[row]) * (observation[col] - mean[col])
+        });
+
+        //the mean moves towards the observation
+        for (mean, n) in self.mean.iter_mut().zip(observation) {
+            *mean += (n - *mean) * weight / self.weight_sum;
+        }
+    }
+
+    pub fn push_rows(&mut self, observations: &DMat) {
+        for row in observations.row_iter() {
+            self.push(row);
+        }
+    }
+
+    //combines the observations of another accumulator of the same variables into this one
+    pub fn merge(&mut self, other: &Self) {
+        assert!(
+            self.mean.len() == other.mean.len(),
+            "Attempted to merge accumulators of different numbers of variables"
+        );
+
+        let weight_sum = self.weight_sum + other.weight_sum;
+        if weight_sum == 0.0 {
+            self.count += other.count;
+            self.weight_square_sum += other.weight_square_sum;
+            return;
+        }
+
+        let deviations: Vec<f64> = self
+            .mean
+            .iter()
+            .zip(&other.mean)
+            .map(|(mean, other)| other - mean)
+            .collect();
+        for (mean, deviation) in self.mean.iter_mut().zip(&deviations) {
+            *mean += deviation * other.weight_sum / weight_sum;
+        }
+
+        let scale = self.weight_sum * other.weight_sum / weight_sum;
+        self.comoment.mutate(|val, row, col| {
+            val + other.comoment[row][col] + scale * deviations[row] * deviations[col]
+        });
+
+        self.count += other.count;
+        self.weight_sum = weight_sum;
+        self.weight_square_sum += other.weight_square_sum;
+    }
+
+    //number of observations pushed
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    #[must_use]
+    pub fn weight_sum(&self) -> f64 {
+        self.weight_sum
+    }
+
+    //the 1 x variables row of means
+    #[must_use]
+    pub fn mean(&self) -> DMat {
+        DMat::generate(1, self.mean.len(), |_, col| self.mean[col])
+    }
+
+    //the 1 x variables row of sample variances
+    #[must_use]
+    pub fn variance(&self) -> DMat {
+        let normalization = self.normalization();
+        DMat::generate(1, self.mean.len(), |_, col| {
+            self.comoment[col][col] / normalization
+        })
+    }
+
+    #[must_use]
+    pub fn covariance(&self) -> DMat {
+        &self.comoment / self.normalization()
+    }
+
+    #[must_use]
+    pub fn correlation(&self) -> DMat {
+        covariance_to_correlation(self.covariance())
+    }
+
+    fn normalization(&self) -> f64 {
+        self.weight_sum - self.weight_square_sum / self.weight_sum
+    }
+}
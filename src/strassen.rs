@@ -0,0 +1,86 @@
+use crate::dmat::DMat;
+
+//Strassen's recursive multiplication, replacing 8 half-size products with 7
+//
+//operands are split into quadrants of ceil(n / 2) rows and columns, padding odd dimensions with zeros,
+//and the recursion falls back to the blocked kernel once any dimension is below the threshold
+//
+//accuracy: the blocked kernel's error is bounded per entry, relative to the sum of |a[i][k] * b[k][j]|
+//Strassen's is only bounded relative to max|a| * max|b|, and grows by roughly a factor of 12 per level
+//of recursion rather than linearly in the inner dimension. entries much smaller than the largest
+//products (e.g. in badly scaled matrices, or results of heavy cancellation) can lose most of their
+//relative accuracy, so the * operator never uses it and mul_strassen is only suited to well scaled operands
+
+//dimensions below this are never split further, however low the threshold is set
+const MIN_STRASSEN_SIZE: usize = 16;
+
+//copies the rows x cols block of a starting at (first_row, first_col), with zeros beyond a's bounds
+fn quadrant(a: &DMat, first_row: usize, first_col: usize, rows: usize, cols: usize) -> DMat {
+    let mut quadrant = DMat::zero(rows, cols);
+    for row in 0..rows.min(a.rows().saturating_sub(first_row)) {
+        let source = &a[first_row + row][first_col..];
+        let len = cols.min(source.len());
+        quadrant[row][..len].copy_from_slice(&source[..len]);
+    }
+    quadrant
+}
+
+fn strassen(a: &DMat, b: &DMat, threshold: usize) -> DMat {
+    let (rows, inner, cols) = (a.rows(), a.cols(), b.cols());
+    if rows.min(inner).min(cols) < threshold {
+        let mut product = DMat::zero(rows, cols);
+        DMat::gemm(1.0, a, b, 0.0, &mut product);
+        return product;
+    }
+
+    let (half_rows, half_inner, half_cols) =
+        (rows.div_ceil(2), inner.div_ceil(2), cols.div_ceil(2));
+
+    let a11 = quadrant(a, 0, 0, half_rows, half_inner);
+    let a12 = quadrant(a, 0, half_inner, half_rows, half_inner);
+    let a21 = quadrant(a, half_rows, 0, half_rows, half_inner);
+    let a22 = quadrant(a, half_rows, half_inner, half_rows, half_inner);
+    let b11 = quadrant(b, 0, 0, half_inner, half_cols);
+    let b12 = quadrant(b, 0, half_cols, half_inner, half_cols);
+    let b21 = quadrant(b, half_inner, 0, half_inner, half_cols);
+    let b22 = quadrant(b, half_inner, half_cols, half_inner, half_cols);
+
+    let m1 = strassen(&(&a11 + &a22), &(&b11 + &b22), threshold);
+    let m2 = strassen(&(&a21 + &a22), &b11, threshold);
+    let m3 = strassen(&a11, &(&b12 - &b22), threshold);
+    let m4 = strassen(&a22, &(&b21 - &b11), threshold);
+    let m5 = strassen(&(&a11 + &a12), &b22, threshold);
+    let m6 = strassen(&(&a21 - &a11), &(&b11 + &b12), threshold);
+    let m7 = strassen(&(&a12 - &a22), &(&b21 + &b22), threshold);
+
+    let c11 = m1.clone() + &m4 - &m5 + &m7;
+    let c12 = m3.clone() + &m5;
+    let c21 = m2.clone() + &m4;
+    let c22 = m1 - &m2 + &m3 + &m6;
+
+    //reassemble the quadrants, dropping the padding
+    let mut product = DMat::zero(rows, cols);
+    for row in 0..rows {
+        let (left, right) = if row < half_rows {
+            (&c11[row], &c12[row])
+        } else {
+            (&c21[row - half_rows], &c22[row - half_rows])
+        };
+        product[row][..half_cols].copy_from_slice(left);
+        product[row][half_cols..].copy_from_slice(&right[..cols - half_cols]);
+    }
+    product
+}
+
+impl DMat {
+    //multiplies using Strassen's algorithm until a dimension falls below the given threshold (at least 16),
+    //see the accuracy trade-off above
+    #[must_use]
+    pub fn mul_strassen(&self, rhs: &DMat, threshold: usize) -> DMat {
+        assert!(
+            self.cols() == rhs.rows(),
+            "Attempted to multiply two non-commutative matrices"
+        );
+        strassen(self, rhs, threshold.max(MIN_STRASSEN_SIZE))
+    }
+}
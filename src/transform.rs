@@ -0,0 +1,361 @@
+use crate::mat::Mat;
+use crate::vector::Vector;
+
+//all transforms act on column vectors (transform * point) in a right-handed coordinate system,
+//with angles in radians and positive rotations being counter-clockwise
+
+//components of a 3D affine transform, which is recomposed as translation * rotation * shear * scale.
+//shear holds the (xy, xz, yz) factors of the unit upper triangular shear matrix, and a reflection
+//is represented by a negative z scale so that rotation is always a proper rotation
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct AffineDecomposition {
+    pub translation: Vector<3>,
+    pub rotation: Mat<3, 3>,
+    pub scale: Vector<3>,
+    pub shear: Vector<3>,
+}
+
+impl AffineDecomposition {
+    #[must_use]
+    pub fn to_matrix(&self) -> Mat<4, 4> {
+        let [xy, xz, yz] = self.shear.to_array();
+        let shear = Mat::from([
+            [1.0, xy, xz, 0.0],
+            [0.0, 1.0, yz, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+
+        Mat::<4, 4>::translation(self.translation)
+            * Mat::from_linear(&self.rotation)
+            * shear
+            * Mat::<4, 4>::scaling(self.scale)
+    }
+}
+
+//3D transforms using homogeneous coordinates
+
+impl Mat<4, 4> {
+    #[must_use]
+    pub fn translation(offset: Vector<3>) -> Self {
+        let [x, y, z] = offset.to_array();
+        Self::from([
+            [1.0, 0.0, 0.0, x],
+            [0.0, 1.0, 0.0, y],
+            [0.0, 0.0, 1.0, z],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    #[must_use]
+    pub fn scaling(factors: Vector<3>) -> Self {
+        let [x, y, z] = factors.to_array();
+        Self::from([
+            [x, 0.0, 0.0, 0.0],
+            [0.0, y, 0.0, 0.0],
+            [0.0, 0.0, z, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    #[must_use]
+    pub fn rotation_x(angle: f64) -> Self {
+        let (sin, cos) = (libm::sin(angle), libm::cos(angle));
+        Self::from([
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, cos, -sin, 0.0],
+            [0.0, sin, cos, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    #[must_use]
+    pub fn rotation_y(angle: f64) -> Self {
+        let (sin, cos) = (libm::sin(angle), libm::cos(angle));
+        Self::from([
+            [cos, 0.0, sin, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [-sin, 0.0, cos, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    #[must_use]
+    pub fn rotation_z(angle: f64) -> Self {
+        let (sin, cos) = (libm::sin(angle), libm::cos(angle));
+        Self::from([
+            [cos, -sin, 0.0, 0.0],
+            [sin, cos, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    //rotation around an arbitrary axis, which does not need to be normalized
+    #[must_use]
+    pub fn rotation_axis_angle(axis: Vector<3>, angle: f64) -> Self {
+        let [x, y, z] = axis
+            .normalize()
+            .expect("Attempted to rotate around the zero vector")
+            .to_array();
+        let (sin, cos) = (libm::sin(angle), libm::cos(angle));
+        let t = 1.0 - cos;
+
+        //rodrigues' rotation formula
+        Self::from([
+            [
+                t * x * x + cos,
+                t * x * y - sin * z,
+                t * x * z + sin * y,
+                0.0,
+            ],
+            [
+                t * x * y + sin * z,
+                t * y * y + cos,
+                t * y * z - sin * x,
+                0.0,
+            ],
+            [
+                t * x * z - sin * y,
+                t * y * z + sin * x,
+                t * z * z + cos,
+                0.0,
+            ],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    //view matrix for a camera at eye looking at target, with the camera facing -z
+    #[must_use]
+    pub fn look_at_rh(eye: Vector<3>, target: Vector<3>, up: Vector<3>) -> Self {
+        let forward = (target - eye)
+            .normalize()
+            .expect("Attempted to look at a target at the eye position");
+        Self::view(eye, forward * -1, up)
+    }
+
+    //view matrix for a camera at eye looking at target, with the camera facing +z
+    #[must_use]
+    pub fn look_at_lh(eye: Vector<3>, target: Vector<3>, up: Vector<3>) -> Self {
+        let forward = (target - eye)
+            .normalize()
+            .expect("Attempted to look at a target at the eye position");
+        Self::view(eye, forward, up)
+    }
+
+    //builds a view matrix whose z axis points along the given (normalized) direction
+    fn view(eye: Vector<3>, z_axis: Vector<3>, up: Vector<3>) -> Self {
+        let x_axis = up
+            .cross(&z_axis)
+            .normalize()
+            .expect("Attempted to build a view matrix with up parallel to the view direction");
+        let y_axis = z_axis.cross(&x_axis);
+
+        let [xx, xy, xz] = x_axis.to_array();
+        let [yx, yy, yz] = y_axis.to_array();
+        let [zx, zy, zz] = z_axis.to_array();
+
+        Self::from([
+            [xx, xy, xz, -x_axis.dot(&eye)],
+            [yx, yy, yz, -y_axis.dot(&eye)],
+            [zx, zy, zz, -z_axis.dot(&eye)],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    //projection matrices map the view volume to OpenGL-style clip space,
+    //with x, y and z all in [-1, 1] and the camera looking down -z
+
+    //perspective projection from a vertical field of view and a width/height aspect ratio
+    #[must_use]
+    pub fn perspective(fov_y: f64, aspect: f64, near: f64, far: f64) -> Self {
+        let f = 1.0 / libm::tan(fov_y / 2.0);
+        Self::from([
+            [f / aspect, 0.0, 0.0, 0.0],
+            [0.0, f, 0.0, 0.0],
+            [
+                0.0,
+                0.0,
+                (far + near) / (near - far),
+                2.0 * far * near / (near - far),
+            ],
+            [0.0, 0.0, -1.0, 0.0],
+        ])
+    }
+
+    #[must_use]
+    pub fn orthographic(left: f64, right: f64, bottom: f64, top: f64, near: f64, far: f64) -> Self {
+        let (width, height, depth) = (right - left, top - bottom, far - near);
+        Self::from([
+            [2.0 / width, 0.0, 0.0, -(right + left) / width],
+            [0.0, 2.0 / height, 0.0, -(top + bottom) / height],
+            [0.0, 0.0, -2.0 / depth, -(far + near) / depth],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    //perspective projection of an arbitrary (possibly off-center) view frustum,
+    //with left, right, bottom and top given on the near plane
+    #[must_use]
+    pub fn frustum(left: f64, right: f64, bottom: f64, top: f64, near: f64, far: f64) -> Self {
+        let (width, height, depth) = (right - left, top - bottom, far - near);
+        Self::from([
+            [2.0 * near / width, 0.0, (right + left) / width, 0.0],
+            [0.0, 2.0 * near / height, (top + bottom) / height, 0.0],
+            [0.0, 0.0, -(far + near) / depth, -2.0 * far * near / depth],
+            [0.0, 0.0, -1.0, 0.0],
+        ])
+    }
+
+    //embeds a linear transform into the upper-left block of a homogeneous transform
+    #[must_use]
+    pub fn from_linear(linear: &Mat<3, 3>) -> Self {
+        Self::generate(|row, col| match (row, col) {
+            (3, 3) => 1.0,
+            (3, _) | (_, 3) => 0.0,
+            _ => linear[row][col],
+        })
+    }
+
+    //upper-left linear block of a homogeneous transform
+    #[must_use]
+    pub fn linear(&self) -> Mat<3, 3> {
+        Mat::generate(|row, col| self[row][col])
+    }
+
+    #[must_use]
+    pub fn translation_part(&self) -> Vector<3> {
+        Vector::from_array([self[0][3], self[1][3], self[2][3]])
+    }
+
+    //splits an affine transform into translation, rotation, shear and scale.
+    //returns None if the bottom row is not [0, 0, 0, 1] or the linear block is singular
+    #[must_use]
+    #[allow(clippy::similar_names)]
+    pub fn decompose_affine(&self) -> Option<AffineDecomposition> {
+        if !self.is_affine() {
+            return None;
+        }
+
+        //gram-schmidt QR decomposition of the linear block's columns,
+        //where the upper triangular factor holds the scale and shear
+        let [c0, c1, c2] = [0, 1, 2].map(|col| Vector::from_array(self.linear().col(col)));
+
+        let scale_x = c0.norm();
+        let q0 = c0.normalize()?;
+
+        let shear_xy = q0.dot(&c1);
+        let v1 = c1 - q0 * shear_xy;
+        let scale_y = v1.norm();
+        let q1 = v1.normalize()?;
+
+        let shear_xz = q0.dot(&c2);
+        let shear_yz = q1.dot(&c2);
+        let v2 = c2 - q0 * shear_xz - q1 * shear_yz;
+        let mut scale_z = v2.norm();
+        let mut q2 = v2.normalize()?;
+
+        //flip the last axis of a reflection into the scale
+        if q0.cross(&q1).dot(&q2) < 0.0 {
+            q2 *= -1;
+            scale_z = -scale_z;
+        }
+
+        let [q0, q1, q2] = [q0, q1, q2].map(|q| q.to_array());
+        let rotation = Mat::<3, 3>::generate(|row, col| [q0, q1, q2][col][row]);
+
+        Some(AffineDecomposition {
+            translation: self.translation_part(),
+            rotation,
+            scale: Vector::from_array([scale_x, scale_y, scale_z]),
+            shear: Vector::from_array([shear_xy / scale_y, shear_xz / scale_z, shear_yz / scale_z]),
+        })
+    }
+
+    //replaces the linear block with its closest orthogonal matrix, keeping the rest of the transform.
+    //returns None if the linear block is singular
+    #[must_use]
+    pub fn orthonormalize_rotation(&self) -> Option<Self> {
+        let (rotation, _) = self.linear().polar_decomposition()?;
+        Some(Self::generate(|row, col| {
+            if row < 3 && col < 3 {
+                rotation[row][col]
+            } else {
+                self[row][col]
+            }
+        }))
+    }
+
+    //a rigid transform is a rotation followed by a translation, preserving distances and handedness
+    #[must_use]
+    pub fn is_rigid_within(&self, tolerance: f64) -> bool {
+        self.is_affine() && self.linear().is_rotation_within(tolerance)
+    }
+
+    //an affine transform has a bottom row of [0, 0, 0, 1], i.e. no projective part
+    #[must_use]
+    #[allow(clippy::float_cmp)]
+    pub fn is_affine(&self) -> bool {
+        self[3] == [0.0, 0.0, 0.0, 1.0]
+    }
+
+    //transforms a point (w = 1), so translation applies and the result is divided by w
+    #[must_use]
+    pub fn transform_point(&self, point: Vector<3>) -> Vector<3> {
+        let [x, y, z] = point.to_array();
+        let [tx, ty, tz, w] = (*self * Vector::from_array([x, y, z, 1.0])).to_array();
+        Vector::from_array([tx / w, ty / w, tz / w])
+    }
+
+    //transforms a direction vector (w = 0), so translation does not apply
+    #[must_use]
+    pub fn transform_vector(&self, vector: Vector<3>) -> Vector<3> {
+        let [x, y, z] = vector.to_array();
+        let [tx, ty, tz, _] = (*self * Vector::from_array([x, y, z, 0.0])).to_array();
+        Vector::from_array([tx, ty, tz])
+    }
+}
+
+//2D transforms using homogeneous coordinates
+
+impl Mat<3, 3> {
+    #[must_use]
+    pub fn translation(offset: Vector<2>) -> Self {
+        let [x, y] = offset.to_array();
+        Self::from([[1.0, 0.0, x], [0.0, 1.0, y], [0.0, 0.0, 1.0]])
+    }
+
+    #[must_use]
+    pub fn scaling(factors: Vector<2>) -> Self {
+        let [x, y] = factors.to_array();
+        Self::from([[x, 0.0, 0.0], [0.0, y, 0.0], [0.0, 0.0, 1.0]])
+    }
+
+    #[must_use]
+    pub fn rotation(angle: f64) -> Self {
+        let (sin, cos) = (libm::sin(angle), libm::cos(angle));
+        Self::from([[cos, -sin, 0.0], [sin, cos, 0.0], [0.0, 0.0, 1.0]])
+    }
+
+    //shear where x' = x + x_factor * y and y' = y + y_factor * x
+    #[must_use]
+    pub fn shearing(x_factor: f64, y_factor: f64) -> Self {
+        Self::from([[1.0, x_factor, 0.0], [y_factor, 1.0, 0.0], [0.0, 0.0, 1.0]])
+    }
+
+    //transforms a point (w = 1), so translation applies and the result is divided by w
+    #[must_use]
+    pub fn transform_point(&self, point: Vector<2>) -> Vector<2> {
+        let [x, y] = point.to_array();
+        let [tx, ty, w] = (*self * Vector::from_array([x, y, 1.0])).to_array();
+        Vector::from_array([tx / w, ty / w])
+    }
+
+    //transforms a direction vector (w = 0), so translation does not apply
+    #[must_use]
+    pub fn transform_vector(&self, vector: Vector<2>) -> Vector<2> {
+        let [x, y] = vector.to_array();
+        let [tx, ty, _] = (*self * Vector::from_array([x, y, 0.0])).to_array();
+        Vector::from_array([tx, ty])
+    }
+}
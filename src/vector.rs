@@ -0,0 +1,116 @@
+use crate::f64_abs;
+use crate::mat::Mat;
+
+//statically sized column vector, so that Mat<R, N> * Vector<N> is a Vector<R>
+pub type Vector<const N: usize> = Mat<N, 1>;
+
+impl<const N: usize> Mat<N, 1> {
+    #[must_use]
+    pub fn from_array<T: Into<f64> + Copy>(values: [T; N]) -> Self {
+        Self::generate(|row, _| values[row].into())
+    }
+
+    #[must_use]
+    pub fn to_array(&self) -> [f64; N] {
+        self.col(0)
+    }
+
+    #[must_use]
+    pub fn dot(&self, rhs: &Self) -> f64 {
+        self.iter()
+            .zip(rhs.iter())
+            .fold(0.0, |acc, (a, b)| acc + a[0] * b[0])
+    }
+
+    #[must_use]
+    pub fn norm_squared(&self) -> f64 {
+        self.dot(self)
+    }
+
+    //euclidean (L2) norm
+    #[must_use]
+    pub fn norm(&self) -> f64 {
+        libm::sqrt(self.norm_squared())
+    }
+
+    //manhattan (L1) norm
+    #[must_use]
+    pub fn norm_l1(&self) -> f64 {
+        self.iter().fold(0.0, |acc, n| acc + f64_abs(n[0]))
+    }
+
+    //maximum (L-infinity) norm
+    #[must_use]
+    pub fn norm_inf(&self) -> f64 {
+        self.iter().fold(0.0, |acc: f64, n| acc.max(f64_abs(n[0])))
+    }
+
+    //returns None for the zero vector, which has no direction
+    #[must_use]
+    pub fn normalize(&self) -> Option<Self> {
+        let norm = self.norm();
+        if norm == 0.0 {
+            None
+        } else {
+            Some(*self / norm)
+        }
+    }
+
+    #[must_use]
+    pub fn outer<const M: usize>(&self, rhs: &Vector<M>) -> Mat<N, M> {
+        *self * rhs.transpose()
+    }
+
+    //angle between the two vectors in radians, NaN if either is the zero vector
+    #[must_use]
+    pub fn angle(&self, rhs: &Self) -> f64 {
+        let cos = self.dot(rhs) / (self.norm() * rhs.norm());
+        //clamp to account for rounding errors on (anti)parallel vectors
+        libm::acos(cos.clamp(-1.0, 1.0))
+    }
+
+    //vector projection of self onto the given vector, NaN if it is the zero vector
+    #[must_use]
+    pub fn project_onto(&self, onto: &Self) -> Self {
+        *onto * (self.dot(onto) / onto.norm_squared())
+    }
+}
+
+impl Mat<3, 1> {
+    #[must_use]
+    pub fn cross(&self, rhs: &Self) -> Self {
+        let (a, b) = (self.to_array(), rhs.to_array());
+        Self::from_array([
+            a[1] * b[2] - a[2] * b[1],
+            a[2] * b[0] - a[0] * b[2],
+            a[0] * b[1] - a[1] * b[0],
+        ])
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod dvector;
+#[cfg(feature = "alloc")]
+pub use dvector::DVector;
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __vector_macro {
+    ( $($e: expr),* ) => {
+        Vector::from_array([ $($e),* ])
+    };
+}
+
+#[doc(inline)]
+pub use __vector_macro as vector;
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __dvector_macro {
+    ( $($e: expr),* ) => {
+        DVector::from([ $($e),* ])
+    };
+}
+
+#[doc(inline)]
+pub use __dvector_macro as dvector;
@@ -0,0 +1,322 @@
+use super::Vector;
+use crate::dmat::DMat;
+use crate::f64_abs;
+use core::fmt::{Display, LowerExp};
+use core::ops::{
+    Add, AddAssign, Deref, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
+
+//dynamically sized column vector, wrapping a single-column DMat
+#[derive(Debug, PartialEq, Clone)]
+pub struct DVector(DMat);
+
+impl Deref for DVector {
+    type Target = DMat;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Index<usize> for DVector {
+    type Output = f64;
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0.as_slice()[index]
+    }
+}
+
+impl IndexMut<usize> for DVector {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.0.as_mut_slice()[index]
+    }
+}
+
+impl Display for DVector {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl LowerExp for DVector {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        LowerExp::fmt(&self.0, f)
+    }
+}
+
+impl<const N: usize, T: Into<f64>> From<[T; N]> for DVector {
+    fn from(value: [T; N]) -> Self {
+        Self(DMat::from(value.map(|n| [n])))
+    }
+}
+
+impl<const N: usize> From<Vector<N>> for DVector {
+    fn from(value: Vector<N>) -> Self {
+        Self(DMat::from(*value))
+    }
+}
+
+impl From<DVector> for DMat {
+    fn from(value: DVector) -> Self {
+        value.0
+    }
+}
+
+impl TryFrom<DMat> for DVector {
+    type Error = DMat;
+    //fails (returning the matrix back) if the matrix has more than one column
+    fn try_from(value: DMat) -> Result<Self, Self::Error> {
+        if value.cols() == 1 {
+            Ok(Self(value))
+        } else {
+            Err(value)
+        }
+    }
+}
+
+impl Add for &DVector {
+    type Output = DVector;
+    fn add(self, rhs: Self) -> Self::Output {
+        DVector(&self.0 + &rhs.0)
+    }
+}
+
+impl Add<&Self> for DVector {
+    type Output = Self;
+    fn add(self, rhs: &Self) -> Self::Output {
+        Self(self.0 + &rhs.0)
+    }
+}
+
+impl Add<Self> for DVector {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl AddAssign for DVector {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl Sub for &DVector {
+    type Output = DVector;
+    fn sub(self, rhs: Self) -> Self::Output {
+        DVector(&self.0 - &rhs.0)
+    }
+}
+
+impl Sub<&Self> for DVector {
+    type Output = Self;
+    fn sub(self, rhs: &Self) -> Self::Output {
+        Self(self.0 - &rhs.0)
+    }
+}
+
+impl Sub<Self> for DVector {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl SubAssign for DVector {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl<T: Into<f64>> Mul<T> for DVector {
+    type Output = Self;
+    fn mul(self, scalar: T) -> Self::Output {
+        Self(self.0 * scalar)
+    }
+}
+
+impl<T: Into<f64>> Mul<T> for &DVector {
+    type Output = DVector;
+    fn mul(self, scalar: T) -> Self::Output {
+        DVector(&self.0 * scalar)
+    }
+}
+
+impl<T: Into<f64>> MulAssign<T> for DVector {
+    fn mul_assign(&mut self, scalar: T) {
+        self.0 *= scalar;
+    }
+}
+
+impl<T: Into<f64>> Div<T> for DVector {
+    type Output = Self;
+    fn div(self, scalar: T) -> Self::Output {
+        Self(self.0 / scalar)
+    }
+}
+
+impl<T: Into<f64>> Div<T> for &DVector {
+    type Output = DVector;
+    fn div(self, scalar: T) -> Self::Output {
+        DVector(&self.0 / scalar)
+    }
+}
+
+impl<T: Into<f64>> DivAssign<T> for DVector {
+    fn div_assign(&mut self, scalar: T) {
+        self.0 /= scalar;
+    }
+}
+
+impl Neg for DVector {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+impl Neg for &DVector {
+    type Output = DVector;
+    fn neg(self) -> Self::Output {
+        DVector(-&self.0)
+    }
+}
+
+impl Mul<&DVector> for &DMat {
+    type Output = DVector;
+    fn mul(self, rhs: &DVector) -> Self::Output {
+        DVector(self * &rhs.0)
+    }
+}
+
+impl Mul<&DVector> for DMat {
+    type Output = DVector;
+    fn mul(self, rhs: &DVector) -> Self::Output {
+        DVector(self * &rhs.0)
+    }
+}
+
+impl Mul<DVector> for DMat {
+    type Output = DVector;
+    fn mul(self, rhs: DVector) -> Self::Output {
+        DVector(self * rhs.0)
+    }
+}
+
+impl DVector {
+    #[must_use]
+    pub fn zero(len: usize) -> Self {
+        Self(DMat::zero(len, 1))
+    }
+
+    #[must_use]
+    pub fn generate<F: Fn(usize) -> f64>(len: usize, f: F) -> Self {
+        Self(DMat::generate_serial(len, 1, |row, _| f(row)))
+    }
+
+    #[must_use]
+    pub fn from_slice(values: &[f64]) -> Self {
+        Self::generate(values.len(), |index| values[index])
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.rows()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[must_use]
+    pub fn as_slice(&self) -> &[f64] {
+        self.0.as_slice()
+    }
+
+    #[must_use]
+    pub fn map<F: Fn(f64) -> f64>(&self, f: F) -> Self {
+        Self::generate(self.len(), |index| f(self.as_slice()[index]))
+    }
+
+    #[must_use]
+    pub fn dot(&self, rhs: &Self) -> f64 {
+        assert!(
+            self.len() == rhs.len(),
+            "Attempted to take dot product of two vectors of different lengths"
+        );
+
+        self.as_slice()
+            .iter()
+            .zip(rhs.as_slice())
+            .fold(0.0, |acc, (a, b)| acc + a * b)
+    }
+
+    #[must_use]
+    pub fn norm_squared(&self) -> f64 {
+        self.dot(self)
+    }
+
+    //euclidean (L2) norm
+    #[must_use]
+    pub fn norm(&self) -> f64 {
+        libm::sqrt(self.norm_squared())
+    }
+
+    //manhattan (L1) norm
+    #[must_use]
+    pub fn norm_l1(&self) -> f64 {
+        self.as_slice().iter().fold(0.0, |acc, n| acc + f64_abs(*n))
+    }
+
+    //maximum (L-infinity) norm
+    #[must_use]
+    pub fn norm_inf(&self) -> f64 {
+        self.as_slice()
+            .iter()
+            .fold(0.0, |acc: f64, n| acc.max(f64_abs(*n)))
+    }
+
+    //returns None for the zero vector, which has no direction
+    #[must_use]
+    pub fn normalize(&self) -> Option<Self> {
+        let norm = self.norm();
+        if norm == 0.0 {
+            None
+        } else {
+            Some(self / norm)
+        }
+    }
+
+    #[must_use]
+    pub fn cross(&self, rhs: &Self) -> Self {
+        assert!(
+            self.len() == 3 && rhs.len() == 3,
+            "Attempted to take cross product of vectors that are not 3-dimensional"
+        );
+
+        let (a, b) = (self, rhs);
+        Self::from([
+            a[1] * b[2] - a[2] * b[1],
+            a[2] * b[0] - a[0] * b[2],
+            a[0] * b[1] - a[1] * b[0],
+        ])
+    }
+
+    #[must_use]
+    pub fn outer(&self, rhs: &Self) -> DMat {
+        DMat::generate(self.len(), rhs.len(), |row, col| self[row] * rhs[col])
+    }
+
+    //angle between the two vectors in radians, NaN if either is the zero vector
+    #[must_use]
+    pub fn angle(&self, rhs: &Self) -> f64 {
+        let cos = self.dot(rhs) / (self.norm() * rhs.norm());
+        //clamp to account for rounding errors on (anti)parallel vectors
+        libm::acos(cos.clamp(-1.0, 1.0))
+    }
+
+    //vector projection of self onto the given vector, NaN if it is the zero vector
+    #[must_use]
+    pub fn project_onto(&self, onto: &Self) -> Self {
+        onto * (self.dot(onto) / onto.norm_squared())
+    }
+}
@@ -0,0 +1,51 @@
+//the sizes used assume 64-bit usize
+#![cfg(all(feature = "alloc", target_pointer_width = "64"))]
+
+use mat_rs::dmat::{dmat, AllocError, DMat};
+
+#[test]
+fn fallible_constructors_match_infallible_ones() {
+    assert_eq!(DMat::try_zero(3, 4), Ok(DMat::zero(3, 4)));
+    assert_eq!(DMat::try_identity(5), Ok(DMat::identity(5)));
+    let f = |row: usize, col: usize| f64::from(u32::try_from(row * 10 + col).unwrap());
+    assert_eq!(DMat::try_generate(4, 2, f), Ok(DMat::generate(4, 2, f)));
+
+    let mat = dmat![1, 2; 3, 4];
+    assert_eq!(mat.try_clone(), Ok(mat.clone()));
+    assert_eq!(DMat::try_zero(0, 7), Ok(DMat::zero(0, 7)));
+}
+
+#[test]
+fn oversized_matrices_are_errors() {
+    assert_eq!(
+        DMat::try_zero(usize::MAX, 2),
+        Err(AllocError::CapacityOverflow)
+    );
+    //fits in usize, but not in an allocation of at most isize::MAX bytes
+    assert_eq!(
+        DMat::try_identity(1 << 32),
+        Err(AllocError::CapacityOverflow)
+    );
+    assert_eq!(
+        DMat::try_generate(1 << 40, 1 << 20, |_, _| 0.0),
+        Err(AllocError::CapacityOverflow)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Attempted to create a matrix with more values than fit in memory")]
+fn zero_panics_on_overflow() {
+    let _ = DMat::zero(usize::MAX, 2);
+}
+
+#[test]
+#[should_panic(expected = "Attempted to create a matrix with more values than fit in memory")]
+fn generate_panics_on_overflow() {
+    let _ = DMat::generate(1 << 33, 1 << 33, |_, _| 0.0);
+}
+
+#[test]
+#[should_panic(expected = "Attempted to create a matrix with more values than fit in memory")]
+fn identity_panics_on_overflow() {
+    let _ = DMat::identity(1 << 33);
+}
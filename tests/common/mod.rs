@@ -0,0 +1,25 @@
+#![allow(dead_code)]
+
+use mat_rs::dmat::DMat;
+
+//deterministic values in [-1, 1), from the splitmix64 hash of the position and seed
+#[must_use]
+pub fn pseudo_random(rows: usize, cols: usize, seed: u64) -> DMat {
+    DMat::generate(rows, cols, |row, col| {
+        let position = u64::try_from(row * cols + col).unwrap();
+        let mut z = (seed << 32 ^ position).wrapping_add(0x9e37_79b9_7f4a_7c15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^= z >> 31;
+        #[allow(clippy::cast_precision_loss)]
+        let unit = (z >> 11) as f64 / (1_u64 << 53) as f64;
+        unit * 2.0 - 1.0
+    })
+}
+
+pub fn assert_close(a: &DMat, b: &DMat, tolerance: f64) {
+    assert_eq!((a.rows(), a.cols()), (b.rows(), b.cols()));
+    for (x, y) in a.as_slice().iter().zip(b.as_slice()) {
+        assert!((x - y).abs() <= tolerance, "{a}\n!=\n{b}");
+    }
+}
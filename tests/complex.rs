@@ -0,0 +1,111 @@
+use mat_rs::cmat::{cmat, CMat};
+use mat_rs::complex::Complex;
+use mat_rs::display::DisplayOptions;
+
+fn assert_close(a: Complex, b: Complex) {
+    assert!(a.approx_eq(&b, 1e-12), "{a} != {b}");
+}
+
+#[test]
+fn cmat_display() {
+    let mat =
+        cmat![Complex::new(1.0, 2.0), Complex::I; Complex::new(-3.5, 0.0), Complex::new(0.0, -1.0)];
+    assert_eq!(format!("{mat}"), "┌ 1+2i 1i  ┐\n└ -3.5 -1i ┘");
+    assert_eq!(
+        format!("{mat:.1}"),
+        "┌ 1.0+2.0i 1.0i  ┐\n└   -3.5   -1.0i ┘"
+    );
+
+    let row = cmat![Complex::new(1.0, -2.0), Complex::new(3.0, 0.0)];
+    assert_eq!(format!("{row:+}"), "[ +1-2i +3 ]");
+}
+
+#[test]
+fn cmat_inverse_and_determinant() {
+    //needs a row swap, as the first column only has a value below the diagonal
+    let mat = cmat![Complex::ZERO, Complex::I; Complex::new(2.0, 1.0), Complex::ONE];
+    assert_close(mat.determinant(), Complex::new(1.0, -2.0));
+
+    let inverse = mat.inverse().unwrap();
+    let product = mat * inverse;
+    for row in 0..2 {
+        for col in 0..2 {
+            let expected = if row == col {
+                Complex::ONE
+            } else {
+                Complex::ZERO
+            };
+            assert_close(product[row][col], expected);
+        }
+    }
+
+    assert!(cmat![Complex::ONE, Complex::I; Complex::I, -Complex::ONE]
+        .inverse()
+        .is_none());
+}
+
+#[test]
+fn cmat_adjoint_checks() {
+    let hermitian = cmat![Complex::new(2.0, 0.0), Complex::new(1.0, -1.0); Complex::new(1.0, 1.0), Complex::new(3.0, 0.0)];
+    assert!(hermitian.is_hermitian());
+    assert_eq!(hermitian.adjoint(), hermitian);
+
+    let s = core::f64::consts::FRAC_1_SQRT_2;
+    let unitary: CMat<2, 2> = cmat![Complex::new(s, 0.0), Complex::new(0.0, s); Complex::new(0.0, s), Complex::new(s, 0.0)];
+    assert!(unitary.is_unitary_within(1e-12));
+    assert!(!hermitian.is_unitary_within(1e-12));
+}
+
+#[cfg(feature = "alloc")]
+mod cdmat {
+    use super::assert_close;
+    use mat_rs::cdmat::{cdmat, CDMat};
+    use mat_rs::complex::Complex;
+    use mat_rs::display::DisplayOptions;
+
+    #[test]
+    fn cdmat_display_matches_cmat() {
+        let mat = cdmat![Complex::new(1.0, 2.0), Complex::I; Complex::new(-3.5, 0.0), Complex::new(0.0, -1.0)];
+        assert_eq!(format!("{mat}"), "┌ 1+2i 1i  ┐\n└ -3.5 -1i ┘");
+        assert_eq!(
+            format!("{mat:.1e}"),
+            "┌ 1.0e0+2.0e0i 1.0e0i  ┐\n└    -3.5e0    -1.0e0i ┘"
+        );
+    }
+
+    #[test]
+    fn cdmat_display_of_empty_and_elided_matrices() {
+        assert_eq!(format!("{}", CDMat::zero(0, 3)), "[ ]");
+        assert_eq!(format!("{}", CDMat::zero(2, 0)), "[ ]");
+
+        let options = DisplayOptions {
+            max_cols: 2,
+            ..DisplayOptions::DEFAULT
+        };
+        let row = CDMat::generate(1, 5, |_, col| {
+            Complex::new(0.0, f64::from(u8::try_from(col).unwrap()))
+        });
+        assert_eq!(format!("{}", row.display_with(options)), "[ 0 … 4i ]");
+    }
+
+    #[test]
+    fn cdmat_inverse_and_determinant() {
+        let mat = cdmat![Complex::ZERO, Complex::I; Complex::new(2.0, 1.0), Complex::ONE];
+        assert_close(mat.determinant(), Complex::new(1.0, -2.0));
+        let product = &mat * &mat.inverse().unwrap();
+        assert_close(product[0][0], Complex::ONE);
+        assert_close(product[0][1], Complex::ZERO);
+        assert_close(product[1][0], Complex::ZERO);
+        assert_close(product[1][1], Complex::ONE);
+    }
+}
+
+#[test]
+fn display_options_apply_to_complex_values() {
+    let mat = cmat![Complex::new(1.25, -0.5)];
+    let options = DisplayOptions {
+        precision: Some(2),
+        ..DisplayOptions::DEFAULT
+    };
+    assert_eq!(format!("{}", mat.display_with(options)), "[ 1.25-0.50i ]");
+}
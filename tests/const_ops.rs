@@ -0,0 +1,50 @@
+use mat_rs::mat::{mat, Mat};
+
+//evaluated at compile time, so each item fails the build if its const fn stops being const
+const A: Mat<2, 3> = Mat::new([[1.5, -2.0, 0.1], [3.0, 0.7, -4.25]]);
+const B: Mat<2, 3> = Mat::new([[0.3, 5.0, -1.0], [-0.2, 2.5, 8.0]]);
+const SQUARE: Mat<3, 3> = Mat::new([[2.0, -1.0, 0.5], [0.0, 3.0, 1.0], [4.0, 0.25, -2.0]]);
+
+const TRANSPOSE: Mat<3, 2> = A.const_transpose();
+const SUM: Mat<2, 3> = A.const_add(&B);
+const DIFFERENCE: Mat<2, 3> = A.const_sub(&B);
+const SCALED: Mat<2, 3> = A.const_scale(-0.3);
+const PRODUCT: Mat<2, 3> = A.const_mul(&SQUARE);
+const GRAM: Mat<2, 2> = A.const_mul(&B.const_transpose());
+const IDENTITY: Mat<3, 3> = Mat::const_identity();
+const ZERO: Mat<2, 3> = Mat::zero();
+
+#[test]
+fn const_items_match_runtime_operators() {
+    assert_eq!(TRANSPOSE, A.transpose());
+    assert_eq!(SUM, A + B);
+    assert_eq!(DIFFERENCE, A - B);
+    assert_eq!(SCALED, A * -0.3);
+    //products are accumulated in the same order, so they are bitwise identical
+    assert_eq!(PRODUCT, A * SQUARE);
+    assert_eq!(GRAM, A * B.transpose());
+    assert_eq!(IDENTITY, Mat::<3, 3>::identity());
+    assert_eq!(ZERO, Mat::<2, 3>::generate(|_, _| 0.0));
+}
+
+#[test]
+fn const_items_hold_the_expected_values() {
+    assert_eq!(TRANSPOSE, mat![1.5, 3.0; -2.0, 0.7; 0.1, -4.25]);
+    assert_eq!(SUM, mat![1.8, 3.0, -0.9; 2.8, 3.2, 3.75]);
+    assert_eq!(SQUARE.const_mul(&IDENTITY), SQUARE);
+    assert_eq!(IDENTITY.const_mul(&SQUARE), SQUARE);
+    assert_eq!(A.const_add(&A.const_scale(-1.0)), ZERO);
+    assert_eq!(DIFFERENCE.const_add(&B), A.const_sub(&B).const_add(&B));
+    assert_eq!(A.as_array(), &[[1.5, -2.0, 0.1], [3.0, 0.7, -4.25]]);
+}
+
+#[test]
+fn const_fns_also_run_at_runtime() {
+    let a = mat![0.5, 1.0; -1.0, 2.0];
+    let b = mat![3.0, -0.5; 0.25, 1.0];
+    assert_eq!(a.const_mul(&b), a * b);
+    assert_eq!(a.const_add(&b), a + b);
+    assert_eq!(a.const_sub(&b), a - b);
+    assert_eq!(a.const_scale(2.5), a * 2.5);
+    assert_eq!(a.const_transpose(), a.transpose());
+}
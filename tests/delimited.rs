@@ -0,0 +1,177 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use common::pseudo_random;
+use mat_rs::delimited::DelimitedError;
+use mat_rs::dmat::{dmat, DMat};
+use mat_rs::mat::{mat, Mat};
+
+#[test]
+fn comma_tab_and_whitespace_delimiters() {
+    let expected = dmat![1.0, 2.5, -3.0; 4e2, 0.0, 6.0];
+    assert_eq!(
+        DMat::from_delimited("1,2.5,-3\n4e2,0,6\n", ','),
+        Ok(expected.clone())
+    );
+    assert_eq!(
+        DMat::from_delimited("1\t2.5\t-3\r\n4e2\t0\t6", '\t'),
+        Ok(expected.clone())
+    );
+    assert_eq!(
+        DMat::from_delimited("  1   2.5 -3\n\t4e2 0\t 6  \n", ' '),
+        Ok(expected.clone())
+    );
+    //padding around fields is ignored
+    assert_eq!(
+        DMat::from_delimited(" 1 , 2.5,-3 \n4e2 ,0, 6", ','),
+        Ok(expected)
+    );
+}
+
+#[test]
+fn quoted_fields() {
+    assert_eq!(
+        DMat::from_delimited("\"1\",\"2\"\n3, \"4\" \n", ','),
+        Ok(dmat![1, 2; 3, 4])
+    );
+
+    //quoted header fields may hold delimiters, escaped quotes and comment characters
+    let input = "\"a,b\",\"say \"\"hi\"\"\",\"# c\"\n1,2,3\n";
+    let (header, mat) = DMat::from_delimited_with_header(input, ',').unwrap();
+    assert_eq!(header, ["a,b", "say \"hi\"", "# c"]);
+    assert_eq!(mat, dmat![1, 2, 3]);
+
+    assert_eq!(
+        DMat::from_delimited("1,\"2\n", ','),
+        Err(DelimitedError::UnterminatedQuote { row: 1, column: 2 })
+    );
+    assert_eq!(
+        DMat::from_delimited("1,\"2\"3\n", ','),
+        Err(DelimitedError::InvalidValue { row: 1, column: 2 })
+    );
+}
+
+#[test]
+fn headers_comments_and_blank_lines_are_skipped() {
+    let input = "# exported values\n\nx,y\n# first row\n1,2\n\n  # indented comment\n3,4\n";
+    let (header, mat) = DMat::from_delimited_with_header(input, ',').unwrap();
+    assert_eq!(header, ["x", "y"]);
+    assert_eq!(mat, dmat![1, 2; 3, 4]);
+
+    //the header may hold a different number of fields than the data
+    let (header, mat) = DMat::from_delimited_with_header("index\n1,2\n", ',').unwrap();
+    assert_eq!((header.len(), mat.cols()), (1, 2));
+
+    let (header, mat) = Mat::<1, 2>::from_delimited_with_header("x y\n5 6\n", ' ').unwrap();
+    assert_eq!(header, ["x", "y"]);
+    assert_eq!(mat, mat![5, 6]);
+
+    assert_eq!(
+        DMat::from_delimited("# only a comment\n\n", ','),
+        Err(DelimitedError::Empty)
+    );
+    assert_eq!(
+        DMat::from_delimited_with_header("x,y\n", ','),
+        Err(DelimitedError::Empty)
+    );
+}
+
+#[test]
+fn ragged_rows_report_the_first_mismatched_column() {
+    //rows are counted over every line of the input
+    assert_eq!(
+        DMat::from_delimited("1,2,3\n# comment\n4,5\n", ','),
+        Err(DelimitedError::RaggedRow {
+            row: 3,
+            column: 3,
+            expected: 3,
+            found: 2
+        })
+    );
+    assert_eq!(
+        DMat::from_delimited("1 2\n3 4 5 6\n", ' '),
+        Err(DelimitedError::RaggedRow {
+            row: 2,
+            column: 3,
+            expected: 2,
+            found: 4
+        })
+    );
+    //a trailing delimiter adds an empty field
+    assert_eq!(
+        DMat::from_delimited("1,2\n3,4,\n", ','),
+        Err(DelimitedError::RaggedRow {
+            row: 2,
+            column: 3,
+            expected: 2,
+            found: 3
+        })
+    );
+}
+
+#[test]
+fn invalid_values_and_dimensions() {
+    assert_eq!(
+        DMat::from_delimited("1,2\n3,four\n", ','),
+        Err(DelimitedError::InvalidValue { row: 2, column: 2 })
+    );
+    assert_eq!(
+        DMat::from_delimited("1,,3\n", ','),
+        Err(DelimitedError::InvalidValue { row: 1, column: 2 })
+    );
+    assert_eq!(
+        Mat::<2, 2>::from_delimited("1,2,3\n4,5,6\n", ','),
+        Err(DelimitedError::WrongDimensions {
+            expected: (2, 2),
+            found: (2, 3)
+        })
+    );
+    assert_eq!(
+        DelimitedError::RaggedRow {
+            row: 4,
+            column: 2,
+            expected: 3,
+            found: 1
+        }
+        .to_string(),
+        "expected 3 columns on row 4, found 1 (from column 2)"
+    );
+}
+
+#[test]
+fn written_values_read_back() {
+    //shortest round-trip representation by default
+    let mat = pseudo_random(5, 4, 1).map(|n| n * 1e3);
+    for delimiter in [',', '\t', ' ', ';'] {
+        let output = mat.to_delimited(delimiter, None);
+        assert_eq!(DMat::from_delimited(&output, delimiter), Ok(mat.clone()));
+    }
+
+    //a fixed number of decimal places rounds
+    let mat = dmat![1.0, 2.346; -0.5, 1e-9];
+    assert_eq!(mat.to_delimited(',', Some(2)), "1.00,2.35\n-0.50,0.00\n");
+    let read = DMat::from_delimited(&mat.to_delimited(',', Some(2)), ',').unwrap();
+    assert!(read
+        .as_slice()
+        .iter()
+        .zip(mat.as_slice())
+        .all(|(a, b)| (a - b).abs() <= 0.005));
+
+    let mut output = String::new();
+    mat![1, 2]
+        .write_delimited_with_header(&mut output, &["a \"b\"", "c,d"], ',', Some(1))
+        .unwrap();
+    assert_eq!(output, "\"a \"\"b\"\"\",\"c,d\"\n1.0,2.0\n");
+    let (header, read) = Mat::<1, 2>::from_delimited_with_header(&output, ',').unwrap();
+    assert_eq!(header, ["a \"b\"", "c,d"]);
+    assert_eq!(read, mat![1, 2]);
+}
+
+#[test]
+#[should_panic(
+    expected = "Attempted to write a header with a different number of columns than the matrix"
+)]
+fn header_of_the_wrong_length() {
+    let _ = dmat![1, 2].write_delimited_with_header(&mut String::new(), &["a"], ',', None);
+}
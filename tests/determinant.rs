@@ -0,0 +1,61 @@
+#[cfg(feature = "alloc")]
+use mat_rs::dmat::{dmat, DMat};
+#[cfg(feature = "alloc")]
+use mat_rs::dmat_col_major::DMatColMajor;
+use mat_rs::mat::{mat, Mat};
+use mat_rs::smat::{smat, SMat};
+
+fn assert_close(a: f64, b: f64) {
+    assert!((a - b).abs() < 1e-12, "{a} != {b}");
+}
+
+#[test]
+fn mat_determinant_needs_pivoting() {
+    //90 degree rotation about z, whose first column only has a non-zero value below the diagonal
+    let rotation = mat![0, -1, 0; 1, 0, 0; 0, 0, 1];
+    assert_close(rotation.determinant(), 1.0);
+
+    let permutation = mat![0, 0, 1; 0, 1, 0; 1, 0, 0];
+    assert_close(permutation.determinant(), -1.0);
+
+    let mat = mat![0, 2, 1, 3; 4, 0, 1, 1; 1, 1, 0, 2; 3, 1, 2, 0];
+    assert_close(mat.determinant(), -12.0);
+}
+
+#[test]
+fn mat_determinant_of_singular_matrix() {
+    assert_close(mat![1, 2, 3; 2, 4, 6; 0, 1, 1].determinant(), 0.0);
+    assert_close(Mat::<3, 3>::zero().determinant(), 0.0);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn dynamic_determinants_agree() {
+    let values = [
+        [0.0, 2.0, 1.0, 3.0],
+        [4.0, 0.0, 1.0, 1.0],
+        [1.0, 1.0, 0.0, 2.0],
+        [3.0, 1.0, 2.0, 0.0],
+    ];
+    let dmat = DMat::from(values);
+    let col_major = DMatColMajor::from(&dmat);
+
+    assert_close(Mat::from(values).determinant(), -12.0);
+    assert_close(SMat::<16>::from(values).determinant(), -12.0);
+    assert_close(dmat.determinant(), -12.0);
+    assert_close(col_major.determinant(), -12.0);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn dynamic_determinant_needs_pivoting() {
+    assert_close(dmat![0, -1, 0; 1, 0, 0; 0, 0, 1].determinant(), 1.0);
+    let col_major = DMatColMajor::from(&dmat![0, 0, 1; 0, 1, 0; 1, 0, 0]);
+    assert_close(col_major.determinant(), -1.0);
+}
+
+#[test]
+fn smat_determinant_needs_pivoting() {
+    let rotation: SMat<9> = smat![0, -1, 0; 1, 0, 0; 0, 0, 1];
+    assert_close(rotation.determinant(), 1.0);
+}
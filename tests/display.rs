@@ -0,0 +1,360 @@
+use mat_rs::display::{Alignment, DisplayOptions, Notation};
+use mat_rs::mat::{mat, Mat};
+use mat_rs::smat::SMat;
+
+fn sample() -> Mat<2, 2> {
+    mat![1.0, -22.5; 300.0, 0.25]
+}
+
+#[test]
+fn default_display() {
+    assert_eq!(sample().to_string(), "┌  1  -22.5 ┐\n└ 300 0.25  ┘");
+    assert_eq!(mat![1, 2, 3].to_string(), "[ 1 2 3 ]");
+    assert_eq!(Mat::<0, 3>::zero().to_string(), "[ ]");
+    assert_eq!(
+        SMat::<8>::from_slice(2, 2, &[1.0, f64::NAN, f64::INFINITY, -0.0]).to_string(),
+        "┌  1  NaN ┐\n└ inf -0  ┘"
+    );
+}
+
+#[test]
+fn precision() {
+    let mat = sample();
+    assert_eq!(format!("{mat:.2}"), "┌  1.00  -22.50 ┐\n└ 300.00  0.25  ┘");
+    assert_eq!(format!("{mat:.0}"), "┌  1  -22 ┐\n└ 300  0  ┘");
+
+    let options = DisplayOptions {
+        precision: Some(1),
+        ..DisplayOptions::DEFAULT
+    };
+    assert_eq!(
+        mat.display_with(options).to_string(),
+        "┌  1.0  -22.5 ┐\n└ 300.0  0.2  ┘"
+    );
+}
+
+#[test]
+fn scientific_notation() {
+    let mat = sample();
+    assert_eq!(format!("{mat:e}"), "┌ 1e0 -2.25e1 ┐\n└ 3e2 2.5e-1  ┘");
+    assert_eq!(format!("{mat:.1e}"), "┌ 1.0e0 -2.2e1 ┐\n└ 3.0e2 2.5e-1 ┘");
+
+    let options = DisplayOptions {
+        precision: Some(3),
+        notation: Notation::Scientific,
+        ..DisplayOptions::DEFAULT
+    };
+    assert_eq!(
+        mat.display_with(options).to_string(),
+        "┌ 1.000e0 -2.250e1 ┐\n└ 3.000e2 2.500e-1 ┘"
+    );
+}
+
+#[test]
+fn alignment_width_and_sign() {
+    let mat = sample();
+    assert_eq!(format!("{mat:<}"), "┌ 1   -22.5 ┐\n└ 300 0.25  ┘");
+    assert_eq!(format!("{mat:>}"), "┌   1 -22.5 ┐\n└ 300  0.25 ┘");
+    assert_eq!(
+        format!("{mat:>8}"),
+        "┌        1    -22.5 ┐\n└      300     0.25 ┘"
+    );
+    //extra padding goes to the right
+    assert_eq!(format!("{mat:^6}"), "┌   1    -22.5  ┐\n└  300    0.25  ┘");
+    assert_eq!(format!("{mat:+}"), "┌  +1  -22.5 ┐\n└ +300 +0.25 ┘");
+
+    let options = DisplayOptions {
+        min_width: 6,
+        alignment: Alignment::Right,
+        sign_plus: true,
+        ..DisplayOptions::DEFAULT
+    };
+    assert_eq!(
+        mat.display_with(options).to_string(),
+        "┌     +1  -22.5 ┐\n└   +300  +0.25 ┘"
+    );
+}
+
+#[test]
+fn formatter_flags_override_options() {
+    let options = DisplayOptions {
+        precision: Some(3),
+        min_width: 6,
+        alignment: Alignment::Left,
+        ..DisplayOptions::DEFAULT
+    };
+    let mat = sample();
+    assert_eq!(
+        format!("{:.1}", mat.display_with(options)),
+        "┌ 1.0    -22.5  ┐\n└ 300.0  0.2    ┘"
+    );
+    assert_eq!(
+        format!("{:>4.0}", mat.display_with(options)),
+        "┌    1  -22 ┐\n└  300    0 ┘"
+    );
+}
+
+#[test]
+fn with_formatter_reads_the_flags() {
+    struct Options;
+    impl core::fmt::Display for Options {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            write!(f, "{:?}", DisplayOptions::DEFAULT.with_formatter(f))
+        }
+    }
+
+    let expected = DisplayOptions {
+        precision: Some(2),
+        min_width: 7,
+        alignment: Alignment::Left,
+        sign_plus: true,
+        ..DisplayOptions::DEFAULT
+    };
+    assert_eq!(format!("{Options:<+7.2}"), format!("{expected:?}"));
+    assert_eq!(
+        format!("{Options}"),
+        format!("{:?}", DisplayOptions::DEFAULT)
+    );
+    assert_eq!(DisplayOptions::default(), DisplayOptions::DEFAULT);
+}
+
+#[test]
+fn elision() {
+    let options = DisplayOptions {
+        max_rows: 1,
+        max_cols: 1,
+        ..DisplayOptions::DEFAULT
+    };
+    assert_eq!(
+        sample().display_with(options).to_string(),
+        "┌ 1 … ┐\n└ ⋮ ⋱ ┘"
+    );
+
+    let wide = Mat::<3, 8>::generate(|row, col| (row * 8 + col) as f64);
+    let options = DisplayOptions {
+        max_rows: 2,
+        max_cols: 3,
+        ..DisplayOptions::DEFAULT
+    };
+    assert_eq!(
+        wide.display_with(options).to_string(),
+        "┌ 0  1  … 7  ┐\n│ ⋮  ⋮  ⋱ ⋮  │\n└ 16 17 … 23 ┘"
+    );
+}
+
+#[test]
+fn default_elides_beyond_20_rows_or_columns() {
+    let within = Mat::<20, 20>::generate(|row, col| (row * 100 + col) as f64);
+    assert!(!within.to_string().contains(['…', '⋮']));
+
+    let beyond = Mat::<21, 21>::generate(|row, col| (row * 100 + col) as f64);
+    let output = beyond.to_string();
+    let lines: Vec<&str> = output.lines().collect();
+    //the first and last 10 rows and columns are shown
+    assert_eq!(lines.len(), 21);
+    assert!(lines[0].starts_with("┌  0    1  "));
+    assert!(lines[0].contains("  9   …  11  "));
+    assert_eq!(lines[10].matches('⋮').count(), 20);
+    assert_eq!(lines[10].matches('⋱').count(), 1);
+    assert!(lines[20].ends_with("2019 2020 ┘"));
+    assert!(!output.contains("1010"));
+
+    let unlimited = beyond.display_with(DisplayOptions::UNLIMITED).to_string();
+    assert!(!unlimited.contains(['…', '⋮']));
+    assert_eq!(unlimited.lines().count(), 21);
+}
+
+#[cfg(feature = "alloc")]
+mod dynamic {
+    use mat_rs::cdmat::CDMat;
+    use mat_rs::complex::Complex;
+    use mat_rs::display::DisplayOptions;
+    use mat_rs::dmat::{dmat, DMat};
+
+    #[test]
+    fn dmat_matches_mat() {
+        let mat = dmat![1.0, -22.5; 300.0, 0.25];
+        assert_eq!(format!("{mat:.2}"), "┌  1.00  -22.50 ┐\n└ 300.00  0.25  ┘");
+        assert_eq!(format!("{mat:e}"), "┌ 1e0 -2.25e1 ┐\n└ 3e2 2.5e-1  ┘");
+        assert_eq!(
+            dmat![f64::NAN, f64::NEG_INFINITY].to_string(),
+            "[ NaN -inf ]"
+        );
+        assert_eq!(DMat::zero(2, 0).to_string(), "[ ]");
+
+        let big = DMat::generate(21, 21, |row, col| (row * 100 + col) as f64);
+        assert_eq!(big.to_string().lines().count(), 21);
+        assert!(big.to_string().contains('⋱'));
+        assert!(!big
+            .display_with(DisplayOptions::UNLIMITED)
+            .to_string()
+            .contains('⋱'));
+    }
+
+    #[test]
+    fn dmat_source_formats() {
+        let mat = dmat![1.0, -22.5; 3e20, 0.25];
+        assert_eq!(
+            mat.display_latex().to_string(),
+            "\\begin{bmatrix}\n1 & -22.5 \\\\\n300000000000000000000 & 0.25\n\\end{bmatrix}"
+        );
+        assert_eq!(
+            mat.display_markdown().to_string(),
+            "| 0 | 1 |\n|--:|--:|\n| 1 | -22.5 |\n| 300000000000000000000 | 0.25 |"
+        );
+        assert_eq!(
+            mat.display_matlab().to_string(),
+            "[1 -22.5; 300000000000000000000 0.25]"
+        );
+        assert_eq!(
+            mat.display_numpy().to_string(),
+            "np.array([[1.0, -22.5], [3e20, 0.25]])"
+        );
+
+        let empty = DMat::zero(0, 3);
+        assert_eq!(
+            empty.display_latex().to_string(),
+            "\\begin{bmatrix}\n\\end{bmatrix}"
+        );
+        assert_eq!(empty.display_markdown().to_string(), "");
+        assert_eq!(empty.display_matlab().to_string(), "zeros(0, 3)");
+        assert_eq!(empty.display_numpy().to_string(), "np.zeros((0, 3))");
+    }
+
+    #[test]
+    fn complex_matrices_use_the_same_options() {
+        let mat = CDMat::generate(1, 2, |_, col| Complex::new(1.5, [2.0, -0.5][col]));
+        assert_eq!(format!("{mat:.1}"), "[ 1.5+2.0i 1.5-0.5i ]");
+        assert_eq!(format!("{mat:+e}"), "[ +1.5e0+2e0i +1.5e0-5e-1i ]");
+    }
+}
+
+fn wide_values() -> Mat<2, 2> {
+    mat![1.0, -22.5; 3e20, 0.25]
+}
+
+#[test]
+fn latex() {
+    let mat = wide_values();
+    assert_eq!(
+        mat.display_latex().to_string(),
+        "\\begin{bmatrix}\n1 & -22.5 \\\\\n300000000000000000000 & 0.25\n\\end{bmatrix}"
+    );
+    //exponents are written as powers of ten
+    assert_eq!(
+        format!("{:e}", mat.display_latex()),
+        "\\begin{bmatrix}\n1 \\times 10^{0} & -2.25 \\times 10^{1} \\\\\n3 \\times 10^{20} & 2.5 \\times 10^{-1}\n\\end{bmatrix}"
+    );
+    assert_eq!(
+        mat![f64::NAN, f64::INFINITY, f64::NEG_INFINITY, -0.0]
+            .display_latex()
+            .to_string(),
+        "\\begin{bmatrix}\n\\text{NaN} & \\infty & -\\infty & -0\n\\end{bmatrix}"
+    );
+    assert_eq!(
+        Mat::<0, 3>::zero().display_latex().to_string(),
+        "\\begin{bmatrix}\n\\end{bmatrix}"
+    );
+}
+
+#[test]
+fn markdown() {
+    let mat = wide_values();
+    assert_eq!(
+        mat.display_markdown().to_string(),
+        "| 0 | 1 |\n|--:|--:|\n| 1 | -22.5 |\n| 300000000000000000000 | 0.25 |"
+    );
+    assert_eq!(
+        format!(
+            "{:+.1}",
+            mat![f64::NAN, f64::INFINITY, -0.5].display_markdown()
+        ),
+        "| 0 | 1 | 2 |\n|--:|--:|--:|\n| NaN | +Inf | -0.5 |"
+    );
+    assert_eq!(Mat::<2, 0>::zero().display_markdown().to_string(), "");
+}
+
+#[test]
+fn matlab() {
+    let mat = wide_values();
+    assert_eq!(
+        mat.display_matlab().to_string(),
+        "[1 -22.5; 300000000000000000000 0.25]"
+    );
+    assert_eq!(
+        format!("{:.1e}", mat.display_matlab()),
+        "[1.0e0 -2.2e1; 3.0e20 2.5e-1]"
+    );
+    assert_eq!(
+        mat![f64::NAN, f64::INFINITY, f64::NEG_INFINITY]
+            .display_matlab()
+            .to_string(),
+        "[NaN Inf -Inf]"
+    );
+    assert_eq!(
+        Mat::<0, 3>::zero().display_matlab().to_string(),
+        "zeros(0, 3)"
+    );
+}
+
+#[test]
+fn numpy() {
+    let mat = wide_values();
+    //values always hold a decimal point or exponent, so python reads them as floats
+    assert_eq!(
+        mat.display_numpy().to_string(),
+        "np.array([[1.0, -22.5], [3e20, 0.25]])"
+    );
+    assert_eq!(
+        format!("{:.0}", mat![1.0, 2.0].display_numpy()),
+        "np.array([[1., 2.]])"
+    );
+    assert_eq!(
+        format!("{:.2}", mat.display_numpy()),
+        "np.array([[1.00, -22.50], [300000000000000000000.00, 0.25]])"
+    );
+    assert_eq!(
+        mat![f64::NAN, f64::INFINITY, f64::NEG_INFINITY, -0.0]
+            .display_numpy()
+            .to_string(),
+        "np.array([[np.nan, np.inf, -np.inf, -0.0]])"
+    );
+    assert_eq!(
+        Mat::<2, 0>::zero().display_numpy().to_string(),
+        "np.zeros((2, 0))"
+    );
+}
+
+#[test]
+fn source_formats_are_never_elided() {
+    let mat = Mat::<25, 25>::generate(|row, col| (row * 100 + col) as f64);
+    let matlab = mat.display_matlab().to_string();
+    assert!(!matlab.contains(['…', '⋮', '⋱']));
+    assert_eq!(matlab.matches(';').count(), 24);
+    assert!(matlab.ends_with(" 2423 2424]"));
+    assert_eq!(
+        mat.display_latex().to_string().matches(" & ").count(),
+        25 * 24
+    );
+    assert_eq!(mat.display_markdown().to_string().lines().count(), 27);
+    assert_eq!(mat.display_numpy().to_string().matches('[').count(), 26);
+}
+
+#[test]
+fn smat_source_formats() {
+    let mat = SMat::<4>::from_slice(1, 2, &[1.0, 2.0]);
+    assert_eq!(
+        mat.display_latex().to_string(),
+        "\\begin{bmatrix}\n1 & 2\n\\end{bmatrix}"
+    );
+    assert_eq!(
+        mat.display_markdown().to_string(),
+        "| 0 | 1 |\n|--:|--:|\n| 1 | 2 |"
+    );
+    assert_eq!(mat.display_matlab().to_string(), "[1 2]");
+    assert_eq!(mat.display_numpy().to_string(), "np.array([[1.0, 2.0]])");
+    assert_eq!(
+        SMat::<4>::zero(0, 2).display_matlab().to_string(),
+        "zeros(0, 2)"
+    );
+}
@@ -0,0 +1,132 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use common::{assert_close, pseudo_random};
+use mat_rs::dmat::{dmat, DMat};
+use mat_rs::pca::{Pca, PcaComponents};
+
+fn assert_values_close(values: &[f64], expected: &[f64], tol: f64) {
+    assert_eq!(values.len(), expected.len());
+    for (value, expected) in values.iter().zip(expected) {
+        assert!(
+            (value - expected).abs() <= tol,
+            "{values:?} != {expected:?}"
+        );
+    }
+}
+
+#[test]
+fn eigenvalues_of_known_matrices() {
+    let eigen = dmat![2, 1; 1, 2].symmetric_eigen().unwrap();
+    assert_values_close(&eigen.values, &[3.0, 1.0], 1e-14);
+    //with equal magnitudes, the sign makes the first component positive
+    let half = core::f64::consts::FRAC_1_SQRT_2;
+    assert_close(&eigen.vectors, &dmat![half, half; half, -half], 1e-14);
+
+    //already diagonal, sorted in decreasing order
+    let eigen = dmat![1, 0, 0; 0, -4, 0; 0, 0, 7].symmetric_eigen().unwrap();
+    assert_values_close(&eigen.values, &[7.0, 1.0, -4.0], 0.0);
+
+    //the 1, -2, 1 second difference matrix has eigenvalues 2 - 2cos(k pi / 4)
+    let eigen = dmat![2, -1, 0; -1, 2, -1; 0, -1, 2]
+        .symmetric_eigen()
+        .unwrap();
+    let root = 2.0_f64.sqrt();
+    assert_values_close(&eigen.values, &[2.0 + root, 2.0, 2.0 - root], 1e-14);
+}
+
+#[test]
+fn eigendecomposition_reconstructs_the_matrix() {
+    let random = pseudo_random(8, 8, 1);
+    let symmetric = &random + &random.transpose();
+    let eigen = symmetric.symmetric_eigen().unwrap();
+
+    assert_close(&eigen.to_matrix(), &symmetric, 1e-12);
+    assert_close(
+        &(eigen.vectors.transpose() * &eigen.vectors),
+        &DMat::identity(8),
+        1e-13,
+    );
+    assert!(eigen.values.windows(2).all(|pair| pair[0] >= pair[1]));
+}
+
+#[test]
+fn eigendecomposition_fails_with_nan_values() {
+    assert_eq!(dmat![1.0, f64::NAN; f64::NAN, 1.0].symmetric_eigen(), None);
+}
+
+#[test]
+fn pca_round_trips_with_every_component() {
+    let data = pseudo_random(30, 4, 2);
+    let pca = Pca::fit(&data, PcaComponents::All).unwrap();
+
+    assert_eq!(pca.n_components(), 4);
+    assert_close(pca.mean(), &data.mean_cols(), 1e-15);
+    assert_close(&pca.inverse_transform(&pca.transform(&data)), &data, 1e-13);
+
+    let total: f64 = pca.explained_variance_ratio().iter().sum();
+    assert!((total - 1.0).abs() < 1e-14);
+
+    //the projected data is uncorrelated, with the explained variances on the diagonal
+    let covariance = pca.transform(&data).covariance();
+    let expected = DMat::generate(4, 4, |row, col| {
+        if row == col {
+            pca.explained_variance()[row]
+        } else {
+            0.0
+        }
+    });
+    assert_close(&covariance, &expected, 1e-14);
+}
+
+#[test]
+fn pca_keeps_enough_components_for_the_threshold() {
+    //most of the variance lies along x = y, a little along x = -y and none along z
+    let data = DMat::generate(40, 3, |row, col| {
+        let t = f64::from(u32::try_from(row).unwrap()) - 19.5;
+        let wobble = if row % 2 == 0 { 0.5 } else { -0.5 };
+        [t + wobble, t - wobble, 3.0][col]
+    });
+
+    let pca = Pca::fit(&data, PcaComponents::VarianceThreshold(0.99)).unwrap();
+    assert_eq!(pca.n_components(), 1);
+    let ratio = pca.explained_variance_ratio()[0];
+    assert!(ratio > 0.99 && ratio < 1.0);
+
+    let pca = Pca::fit(&data, PcaComponents::VarianceThreshold(ratio + 1e-9)).unwrap();
+    assert_eq!(pca.n_components(), 2);
+
+    let pca = Pca::fit(&data, PcaComponents::Count(5)).unwrap();
+    assert_eq!(pca.n_components(), 3);
+    assert_values_close(
+        pca.explained_variance_ratio(),
+        &[ratio, 1.0 - ratio, 0.0],
+        1e-14,
+    );
+
+    //two components lose nothing
+    let pca = Pca::fit(&data, PcaComponents::Count(2)).unwrap();
+    assert_close(&pca.inverse_transform(&pca.transform(&data)), &data, 1e-12);
+}
+
+#[test]
+fn pca_of_constant_data() {
+    let data = DMat::generate(10, 3, |_, col| [1.0, -2.0, 5.0][col]);
+
+    let pca = Pca::fit(&data, PcaComponents::All).unwrap();
+    assert_eq!(pca.n_components(), 3);
+    assert_values_close(pca.explained_variance(), &[0.0; 3], 0.0);
+    assert_values_close(pca.explained_variance_ratio(), &[0.0; 3], 0.0);
+
+    let pca = Pca::fit(&data, PcaComponents::VarianceThreshold(0.95)).unwrap();
+    assert_eq!(pca.n_components(), 0);
+    let projected = pca.transform(&data);
+    assert_eq!((projected.rows(), projected.cols()), (10, 0));
+    assert_close(&pca.inverse_transform(&projected), &data, 0.0);
+}
+
+#[test]
+fn pca_needs_two_observations() {
+    assert_eq!(Pca::fit(&dmat![1, 2, 3], PcaComponents::All), None);
+}
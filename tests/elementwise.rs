@@ -0,0 +1,163 @@
+use mat_rs::mat::{mat, Mat};
+
+#[test]
+fn mat_zip_map_hadamard_and_division() {
+    let a = mat![1.0, -2.0, 3.0; 4.0, 5.0, -6.0];
+    let b = mat![2.0, 4.0, -0.5; 8.0, 0.25, 3.0];
+    assert_eq!(a.zip_map(&b, f64::max), mat![2.0, 4.0, 3.0; 8.0, 5.0, 3.0]);
+    assert_eq!(a.hadamard(&b), mat![2.0, -8.0, -1.5; 32.0, 1.25, -18.0]);
+    assert_eq!(
+        a.elementwise_div(&b),
+        mat![0.5, -0.5, -6.0; 0.5, 20.0, -2.0]
+    );
+    //division by zero follows IEEE 754 instead of panicking
+    let quotient = mat![1.0, -1.0, 0.0].elementwise_div(&Mat::zero());
+    assert_eq!(quotient[0][..2], [f64::INFINITY, f64::NEG_INFINITY]);
+    assert!(quotient[0][2].is_nan());
+}
+
+#[test]
+fn mat_row_and_column_variants() {
+    let a = mat![1.0, 2.0, 3.0; 4.0, 5.0, 6.0];
+    let row = mat![10.0, 20.0, 30.0];
+    let col = mat![1.0; -1.0];
+    assert_eq!(
+        a.zip_map_row(&row, |a, b| a + b),
+        mat![11.0, 22.0, 33.0; 14.0, 25.0, 36.0]
+    );
+    assert_eq!(
+        a.zip_map_col(&col, |a, b| a * b),
+        mat![1.0, 2.0, 3.0; -4.0, -5.0, -6.0]
+    );
+    assert_eq!(
+        Mat::<2, 3>::broadcast_row(&row),
+        mat![10.0, 20.0, 30.0; 10.0, 20.0, 30.0]
+    );
+    assert_eq!(
+        Mat::<2, 3>::broadcast_col(&col),
+        mat![1.0, 1.0, 1.0; -1.0, -1.0, -1.0]
+    );
+    assert_eq!(
+        a.zip_map_row(&row, |a, b| a - b),
+        a - Mat::broadcast_row(&row)
+    );
+    assert_eq!(
+        a.zip_map_col(&col, |a, b| a / b),
+        a.elementwise_div(&Mat::broadcast_col(&col))
+    );
+}
+
+#[test]
+fn mat_zero_size_operands() {
+    let empty = Mat::<0, 3>::zero();
+    assert_eq!(empty.hadamard(&empty), empty);
+    assert_eq!(
+        Mat::<0, 3>::broadcast_row(&mat![1.0, 2.0, 3.0]),
+        Mat::<0, 3>::zero()
+    );
+    assert_eq!(
+        Mat::<2, 0>::zero().zip_map_col(&mat![1.0; 2.0], |a, b| a + b),
+        Mat::<2, 0>::zero()
+    );
+}
+
+#[cfg(feature = "alloc")]
+mod dynamic {
+    use mat_rs::dmat::{dmat, DMat};
+
+    fn sample() -> DMat {
+        dmat![1.0, -2.0, 3.0; 4.0, 5.0, -6.0]
+    }
+
+    #[test]
+    fn zip_map_hadamard_and_division() {
+        let a = sample();
+        let b = dmat![2.0, 4.0, -0.5; 8.0, 0.25, 3.0];
+        assert_eq!(a.zip_map(&b, f64::max), dmat![2.0, 4.0, 3.0; 8.0, 5.0, 3.0]);
+        assert_eq!(a.hadamard(&b), dmat![2.0, -8.0, -1.5; 32.0, 1.25, -18.0]);
+        assert_eq!(
+            a.elementwise_div(&b),
+            dmat![0.5, -0.5, -6.0; 0.5, 20.0, -2.0]
+        );
+        assert_eq!(a.broadcast_add(&b), &a + &b);
+        assert_eq!(a.broadcast_sub(&b), &a - &b);
+    }
+
+    #[test]
+    fn row_column_and_scalar_broadcasting() {
+        let a = sample();
+        let row = dmat![10.0, 20.0, 30.0];
+        let col = dmat![1.0; -1.0];
+        let scalar = dmat![2.0];
+
+        let expected = dmat![11.0, 18.0, 33.0; 14.0, 25.0, 24.0];
+        assert_eq!(a.broadcast_add(&row), expected);
+        //broadcasting is symmetric
+        assert_eq!(row.broadcast_add(&a), expected);
+        assert_eq!(a.hadamard(&col), dmat![1.0, -2.0, 3.0; -4.0, -5.0, 6.0]);
+        assert_eq!(
+            col.broadcast_sub(&a),
+            dmat![0.0, 3.0, -2.0; -5.0, -6.0, 5.0]
+        );
+        assert_eq!(a.elementwise_div(&scalar), &a * 0.5);
+        assert_eq!(scalar.broadcast_sub(&a), a.map(|n| 2.0 - n));
+
+        //a row and a column broadcast to their outer combination
+        assert_eq!(
+            row.broadcast_add(&col),
+            dmat![11.0, 21.0, 31.0; 9.0, 19.0, 29.0]
+        );
+
+        assert_eq!(
+            row.broadcast_to(2, 3),
+            dmat![10.0, 20.0, 30.0; 10.0, 20.0, 30.0]
+        );
+        assert_eq!(col.broadcast_to(2, 2), dmat![1.0, 1.0; -1.0, -1.0]);
+        assert_eq!(scalar.broadcast_to(1, 3), dmat![2.0, 2.0, 2.0]);
+        assert_eq!(a.broadcast_to(2, 3), a);
+    }
+
+    #[test]
+    fn zero_size_operands() {
+        let empty = DMat::zero(0, 3);
+        assert_eq!(empty.hadamard(&empty), empty);
+        //a dimension of size 1 is repeated zero times to match an empty one
+        assert_eq!(empty.broadcast_add(&dmat![1.0, 2.0, 3.0]), empty);
+        assert_eq!(
+            dmat![1.0; 2.0].zip_map(&DMat::zero(2, 0), f64::max),
+            DMat::zero(2, 0)
+        );
+        assert_eq!(dmat![5.0].broadcast_to(0, 0), DMat::zero(0, 0));
+        assert_eq!(dmat![1.0, 2.0].broadcast_to(0, 2), DMat::zero(0, 2));
+    }
+
+    #[test]
+    #[should_panic(expected = "Attempted to broadcast matrices of incompatible sizes")]
+    fn incompatible_rows_panic() {
+        let _ = sample().hadamard(&DMat::zero(3, 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "Attempted to broadcast matrices of incompatible sizes")]
+    fn incompatible_columns_panic() {
+        let _ = sample().broadcast_add(&dmat![1.0, 2.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Attempted to broadcast matrices of incompatible sizes")]
+    fn empty_and_non_empty_dimensions_panic() {
+        let _ = DMat::zero(0, 3).elementwise_div(&DMat::zero(2, 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "Attempted to broadcast a matrix to an incompatible size")]
+    fn broadcast_to_smaller_size_panics() {
+        let _ = sample().broadcast_to(1, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "Attempted to broadcast a matrix to an incompatible size")]
+    fn broadcast_to_other_length_panics() {
+        let _ = dmat![1.0, 2.0].broadcast_to(2, 3);
+    }
+}
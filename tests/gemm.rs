@@ -0,0 +1,42 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use common::{assert_close, pseudo_random};
+use mat_rs::dmat::DMat;
+
+fn naive_product(a: &DMat, b: &DMat) -> DMat {
+    DMat::generate(a.rows(), b.cols(), |row, col| {
+        (0..a.cols()).fold(0.0, |acc, k| acc + a[row][k] * b[k][col])
+    })
+}
+
+#[test]
+fn product_is_bitwise_identical_to_naive_loop() {
+    //dimensions straddling the block and band sizes
+    for (rows, inner, cols) in [(1, 1, 1), (33, 129, 257), (70, 300, 5), (3, 0, 4)] {
+        let a = pseudo_random(rows, inner, 1);
+        let b = pseudo_random(inner, cols, 2);
+        assert_eq!(&a * &b, naive_product(&a, &b));
+    }
+}
+
+#[test]
+fn gemm_accumulates_scaled_product() {
+    let a = pseudo_random(40, 50, 3);
+    let b = pseudo_random(50, 30, 4);
+    let c = pseudo_random(40, 30, 5);
+
+    let mut result = c.clone();
+    DMat::gemm(2.0, &a, &b, 0.5, &mut result);
+    assert_close(&result, &(naive_product(&a, &b) * 2.0 + c * 0.5), 1e-12);
+}
+
+#[test]
+fn gemm_ignores_c_when_beta_is_zero() {
+    let a = pseudo_random(4, 3, 6);
+    let b = pseudo_random(3, 2, 7);
+    let mut c = DMat::generate(4, 2, |_, _| f64::NAN);
+    DMat::gemm(1.0, &a, &b, 0.0, &mut c);
+    assert_eq!(c, naive_product(&a, &b));
+}
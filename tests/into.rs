@@ -0,0 +1,77 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use common::pseudo_random;
+use mat_rs::dmat::DMat;
+
+#[test]
+fn mul_assign_matches_product() {
+    //row lengths on both sides of the stack scratch buffer
+    for size in [3, 64, 65, 150] {
+        let a = pseudo_random(7, size, 1);
+        let b = pseudo_random(size, size, 2);
+        let expected = &a * &b;
+
+        let mut in_place = a.clone();
+        let buffer = in_place.as_slice().as_ptr();
+        in_place *= &b;
+        assert_eq!(in_place, expected);
+        //longer rows are replaced by the product instead of allocating a scratch row
+        assert_eq!(in_place.as_slice().as_ptr() == buffer, size <= 64);
+
+        let mut with_scratch = a.clone();
+        let buffer = with_scratch.as_slice().as_ptr();
+        with_scratch.mul_assign_with(&b, &mut Vec::new());
+        assert_eq!(with_scratch, expected);
+        assert_eq!(with_scratch.as_slice().as_ptr(), buffer);
+    }
+}
+
+#[test]
+fn mul_assign_with_reuses_scratch() {
+    let rhs = pseudo_random(200, 200, 3);
+    let mut mat = pseudo_random(4, 200, 4);
+    let mut scratch = Vec::new();
+
+    mat.mul_assign_with(&rhs, &mut scratch);
+    let (scratch_buffer, mat_buffer) = (scratch.as_ptr(), mat.as_slice().as_ptr());
+    for _ in 0..3 {
+        mat.mul_assign_with(&rhs, &mut scratch);
+    }
+    assert_eq!(scratch.as_ptr(), scratch_buffer);
+    assert_eq!(mat.as_slice().as_ptr(), mat_buffer);
+}
+
+#[test]
+fn mul_assign_by_non_square_matrix_resizes() {
+    let a = pseudo_random(3, 4, 5);
+    let b = pseudo_random(4, 2, 6);
+    let mut mat = a.clone();
+    mat.mul_assign_with(&b, &mut Vec::new());
+    assert_eq!(mat, &a * &b);
+    assert_eq!((mat.rows(), mat.cols()), (3, 2));
+}
+
+#[test]
+fn into_variants_match_operators() {
+    let a = pseudo_random(5, 6, 7);
+    let b = pseudo_random(5, 6, 8);
+    let c = pseudo_random(6, 3, 9);
+    let mut out = DMat::zero(5, 6);
+    let buffer = out.as_slice().as_ptr();
+
+    DMat::add_into(&a, &b, &mut out);
+    assert_eq!(out, &a + &b);
+    DMat::sub_into(&a, &b, &mut out);
+    assert_eq!(out, &a - &b);
+    DMat::scale_into(&a, 2.5, &mut out);
+    assert_eq!(out, &a * 2.5);
+    DMat::transpose_into(&a, &mut out);
+    assert_eq!(out, a.transpose());
+    //every result so far has 30 values, so the buffer was kept
+    assert_eq!(out.as_slice().as_ptr(), buffer);
+
+    DMat::mul_into(&a, &c, &mut out);
+    assert_eq!(out, &a * &c);
+}
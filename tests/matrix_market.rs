@@ -0,0 +1,256 @@
+#![cfg(feature = "alloc")]
+
+use mat_rs::dmat::{dmat, AllocError, DMat};
+use mat_rs::matrix_market::{
+    MatrixMarketError, MatrixMarketField, MatrixMarketFormat, MatrixMarketHeader,
+    MatrixMarketSymmetry, MatrixMarketWriteError,
+};
+
+fn header(
+    format: MatrixMarketFormat,
+    field: MatrixMarketField,
+    symmetry: MatrixMarketSymmetry,
+) -> MatrixMarketHeader {
+    MatrixMarketHeader {
+        format,
+        field,
+        symmetry,
+    }
+}
+
+fn write(mat: &DMat, header: &MatrixMarketHeader) -> Result<String, MatrixMarketWriteError> {
+    let mut output = String::new();
+    mat.write_matrix_market(&mut output, header)?;
+    Ok(output)
+}
+
+#[test]
+fn round_trip_every_supported_header() {
+    let general = dmat![1.5, 0.0, -2.0; 0.0, 3.0, 0.25];
+    let symmetric = dmat![1, 2, 0; 2, 0, -4; 0, -4, 5];
+    let skew = dmat![0, 2, -1; -2, 0, 3; 1, -3, 0];
+
+    for format in [MatrixMarketFormat::Array, MatrixMarketFormat::Coordinate] {
+        let real = header(
+            format,
+            MatrixMarketField::Real,
+            MatrixMarketSymmetry::General,
+        );
+        let output = write(&general, &real).unwrap();
+        assert_eq!(DMat::from_matrix_market(&output).unwrap(), general);
+
+        for field in [MatrixMarketField::Real, MatrixMarketField::Integer] {
+            let output = write(
+                &symmetric,
+                &header(format, field, MatrixMarketSymmetry::Symmetric),
+            );
+            assert_eq!(
+                DMat::from_matrix_market(&output.unwrap()).unwrap(),
+                symmetric
+            );
+
+            let output = write(
+                &skew,
+                &header(format, field, MatrixMarketSymmetry::SkewSymmetric),
+            );
+            assert_eq!(DMat::from_matrix_market(&output.unwrap()).unwrap(), skew);
+        }
+    }
+
+    let pattern = header(
+        MatrixMarketFormat::Coordinate,
+        MatrixMarketField::Pattern,
+        MatrixMarketSymmetry::Symmetric,
+    );
+    let output = write(&symmetric, &pattern).unwrap();
+    assert_eq!(
+        DMat::from_matrix_market(&output).unwrap(),
+        symmetric.map(|n| if n == 0.0 { 0.0 } else { 1.0 })
+    );
+}
+
+#[test]
+fn symmetric_output_only_lists_the_lower_triangle() {
+    let symmetric = dmat![1, 2; 2, 3];
+    let output = write(
+        &symmetric,
+        &header(
+            MatrixMarketFormat::Array,
+            MatrixMarketField::Real,
+            MatrixMarketSymmetry::Symmetric,
+        ),
+    )
+    .unwrap();
+    assert_eq!(
+        output,
+        "%%MatrixMarket matrix array real symmetric\n2 2\n1\n2\n3\n"
+    );
+    assert_eq!(
+        symmetric.to_matrix_market(),
+        "%%MatrixMarket matrix array real general\n2 2\n1\n2\n2\n3\n"
+    );
+}
+
+#[test]
+fn headers_that_cannot_hold_the_matrix_are_errors() {
+    let asymmetric = dmat![1, 2; 5, 3];
+    let symmetric = header(
+        MatrixMarketFormat::Coordinate,
+        MatrixMarketField::Real,
+        MatrixMarketSymmetry::Symmetric,
+    );
+    assert_eq!(
+        write(&asymmetric, &symmetric),
+        Err(MatrixMarketWriteError::NotSymmetric { row: 1, col: 0 })
+    );
+    assert_eq!(
+        write(&dmat![1, 2, 3], &symmetric),
+        Err(MatrixMarketWriteError::NotSquare)
+    );
+
+    //skew-symmetric matrices have a zero diagonal, which is not stored
+    let skew = header(
+        MatrixMarketFormat::Array,
+        MatrixMarketField::Real,
+        MatrixMarketSymmetry::SkewSymmetric,
+    );
+    assert_eq!(
+        write(&dmat![1, 2; -2, 0], &skew),
+        Err(MatrixMarketWriteError::NotSymmetric { row: 0, col: 0 })
+    );
+
+    let pattern_array = header(
+        MatrixMarketFormat::Array,
+        MatrixMarketField::Pattern,
+        MatrixMarketSymmetry::General,
+    );
+    assert_eq!(
+        write(&asymmetric, &pattern_array),
+        Err(MatrixMarketWriteError::PatternArray)
+    );
+
+    let integer = header(
+        MatrixMarketFormat::Array,
+        MatrixMarketField::Integer,
+        MatrixMarketSymmetry::General,
+    );
+    assert_eq!(
+        write(&dmat![1.0, 2.5], &integer),
+        Err(MatrixMarketWriteError::NotInteger { row: 0, col: 1 })
+    );
+    assert_eq!(
+        write(&dmat![1.0, 1e19], &integer),
+        Err(MatrixMarketWriteError::NotInteger { row: 0, col: 1 })
+    );
+    assert_eq!(
+        write(&dmat![f64::NAN], &integer),
+        Err(MatrixMarketWriteError::NotInteger { row: 0, col: 0 })
+    );
+    //the smallest i64 is a power of two, and can be written and read back
+    let min = dmat![-9_223_372_036_854_775_808.0];
+    assert_eq!(
+        DMat::from_matrix_market(&write(&min, &integer).unwrap()).unwrap(),
+        min
+    );
+}
+
+#[test]
+fn invalid_input_is_an_error() {
+    assert_eq!(
+        DMat::from_matrix_market("1 2\n"),
+        Err(MatrixMarketError::MissingHeader)
+    );
+    assert_eq!(
+        DMat::from_matrix_market("%%MatrixMarket matrix coordinate real general\n2 2 1\n3 1 1.0\n"),
+        Err(MatrixMarketError::IndexOutOfBounds { line: 3 })
+    );
+    assert_eq!(
+        DMat::from_matrix_market("%%MatrixMarket matrix array real general\n2 1\n1\n"),
+        Err(MatrixMarketError::WrongEntryCount {
+            expected: 2,
+            found: 1
+        })
+    );
+    assert_eq!(
+        DMat::from_matrix_market("%%MatrixMarket matrix array integer general\n1 1\n1.5\n"),
+        Err(MatrixMarketError::InvalidEntry { line: 3 })
+    );
+}
+
+#[test]
+fn coordinate_entries_outside_the_stored_triangle_are_errors() {
+    let symmetric = "%%MatrixMarket matrix coordinate real symmetric\n2 2 2\n";
+    assert_eq!(
+        DMat::from_matrix_market(&format!("{symmetric}1 1 4\n2 1 -1\n")),
+        Ok(dmat![4, -1; -1, 0])
+    );
+    assert_eq!(
+        DMat::from_matrix_market(&format!("{symmetric}1 1 4\n1 2 -1\n")),
+        Err(MatrixMarketError::InvalidEntry { line: 4 })
+    );
+
+    //the diagonal of a skew-symmetric matrix is not stored, as it is always zero
+    let skew = "%%MatrixMarket matrix coordinate real skew-symmetric\n2 2 1\n";
+    assert_eq!(
+        DMat::from_matrix_market(&format!("{skew}2 1 3\n")),
+        Ok(dmat![0, -3; 3, 0])
+    );
+    assert_eq!(
+        DMat::from_matrix_market(&format!("{skew}2 2 3\n")),
+        Err(MatrixMarketError::InvalidEntry { line: 3 })
+    );
+    assert_eq!(
+        DMat::from_matrix_market(&format!("{skew}1 2 3\n")),
+        Err(MatrixMarketError::InvalidEntry { line: 3 })
+    );
+}
+
+#[test]
+fn repeated_coordinate_entries_are_errors() {
+    let input = "%%MatrixMarket matrix coordinate real general\n2 2 4\n1 1 1\n2 2 2\n% comment\n2 1 3\n2 2 4\n";
+    assert_eq!(
+        DMat::from_matrix_market(input),
+        Err(MatrixMarketError::DuplicateEntry { line: 7 })
+    );
+
+    let input = "%%MatrixMarket matrix coordinate pattern symmetric\n3 3 2\n3 1\n3 1\n";
+    assert_eq!(
+        DMat::from_matrix_market(input),
+        Err(MatrixMarketError::DuplicateEntry { line: 4 })
+    );
+}
+
+#[test]
+fn sizes_that_cannot_be_held_are_errors() {
+    //more entries than the (stored part of the) matrix has values
+    assert_eq!(
+        DMat::from_matrix_market("%%MatrixMarket matrix coordinate real general\n2 2 5\n"),
+        Err(MatrixMarketError::InvalidSize { line: 2 })
+    );
+    assert_eq!(
+        DMat::from_matrix_market("%%MatrixMarket matrix coordinate real skew-symmetric\n3 3 4\n"),
+        Err(MatrixMarketError::InvalidSize { line: 2 })
+    );
+    assert_eq!(
+        DMat::from_matrix_market("%%MatrixMarket matrix coordinate real symmetric\n2 3 1\n"),
+        Err(MatrixMarketError::InvalidSize { line: 2 })
+    );
+    assert_eq!(
+        DMat::from_matrix_market("%%MatrixMarket matrix array real general\n2 2 4\n"),
+        Err(MatrixMarketError::InvalidSize { line: 2 })
+    );
+
+    //the size line is not trusted with an allocation that cannot succeed
+    assert_eq!(
+        DMat::from_matrix_market(
+            "%%MatrixMarket matrix coordinate real general\n2147483648 2147483648 0\n"
+        ),
+        Err(MatrixMarketError::Alloc(AllocError::CapacityOverflow))
+    );
+    assert_eq!(
+        DMat::from_matrix_market(
+            "%%MatrixMarket matrix coordinate real general\n18446744073709551615 2 0\n"
+        ),
+        Err(MatrixMarketError::InvalidSize { line: 2 })
+    );
+}
@@ -0,0 +1,104 @@
+#![cfg(feature = "alloc")]
+
+use mat_rs::dmat::{dmat, DMat};
+use mat_rs::npy::{NpyDtype, NpyError, NpyOrder};
+
+//a version 1.0 buffer with the given header dict and data, padded as numpy does
+fn npy(dict: &str, data: &[u8]) -> Vec<u8> {
+    let mut header = String::from(dict);
+    while (10 + header.len() + 1) % 64 != 0 {
+        header.push(' ');
+    }
+    header.push('\n');
+
+    let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+    bytes.extend_from_slice(&u16::try_from(header.len()).unwrap().to_le_bytes());
+    bytes.extend_from_slice(header.as_bytes());
+    bytes.extend_from_slice(data);
+    bytes
+}
+
+#[test]
+fn round_trip_every_dtype_and_order() {
+    let mat = dmat![1.5, -2.0, 0.25; 1e10, f64::INFINITY, -0.0];
+    for dtype in [
+        NpyDtype::F64Le,
+        NpyDtype::F64Be,
+        NpyDtype::F32Le,
+        NpyDtype::F32Be,
+    ] {
+        for order in [NpyOrder::C, NpyOrder::Fortran] {
+            let bytes = mat.to_npy(dtype, order);
+            //the data starts on a 64 byte boundary
+            let header_len = usize::from(u16::from_le_bytes([bytes[8], bytes[9]]));
+            assert_eq!((10 + header_len) % 64, 0);
+            assert_eq!(DMat::from_npy(&bytes).unwrap(), mat);
+        }
+    }
+
+    let empty = DMat::zero(0, 3);
+    let bytes = empty.to_npy(NpyDtype::F64Le, NpyOrder::C);
+    assert_eq!(DMat::from_npy(&bytes).unwrap(), empty);
+}
+
+#[test]
+fn f32_output_rounds_values() {
+    let bytes = dmat![0.1].to_npy(NpyDtype::F32Le, NpyOrder::C);
+    assert_eq!(DMat::from_npy(&bytes).unwrap(), dmat![f64::from(0.1_f32)]);
+}
+
+#[test]
+fn reads_numpy_layouts() {
+    let data: Vec<u8> = [1.0_f64, 2.0, 3.0, 4.0, 5.0, 6.0]
+        .iter()
+        .flat_map(|n| n.to_le_bytes())
+        .collect();
+
+    let c_order = npy(
+        "{'descr': '<f8', 'fortran_order': False, 'shape': (2, 3), }",
+        &data,
+    );
+    assert_eq!(DMat::from_npy(&c_order).unwrap(), dmat![1, 2, 3; 4, 5, 6]);
+
+    let fortran_order = npy(
+        "{'descr': '<f8', 'fortran_order': True, 'shape': (2, 3), }",
+        &data,
+    );
+    assert_eq!(
+        DMat::from_npy(&fortran_order).unwrap(),
+        dmat![1, 3, 5; 2, 4, 6]
+    );
+}
+
+#[test]
+fn invalid_buffers_are_errors() {
+    assert_eq!(DMat::from_npy(b"NUMPY"), Err(NpyError::InvalidMagic));
+    assert_eq!(
+        DMat::from_npy(b"\x93NUMPY\x01\x00\xff\x00"),
+        Err(NpyError::UnexpectedEnd)
+    );
+    assert_eq!(
+        DMat::from_npy(&npy(
+            "{'descr': '<i8', 'fortran_order': False, 'shape': (1, 1), }",
+            &[0; 8]
+        )),
+        Err(NpyError::UnsupportedDtype("<i8".to_string()))
+    );
+    assert_eq!(
+        DMat::from_npy(&npy(
+            "{'descr': '<f8', 'fortran_order': False, 'shape': (3,), }",
+            &[0; 24]
+        )),
+        Err(NpyError::UnsupportedShape(vec![3]))
+    );
+    assert_eq!(
+        DMat::from_npy(&npy(
+            "{'descr': '<f8', 'fortran_order': False, 'shape': (1, 2), }",
+            &[0; 8]
+        )),
+        Err(NpyError::DataLength {
+            expected: 16,
+            found: 8
+        })
+    );
+}
@@ -0,0 +1,79 @@
+#![cfg(feature = "parallel")]
+
+mod common;
+
+use common::pseudo_random;
+use mat_rs::dmat::DMat;
+
+#[test]
+fn parallel_variants_match_serial_ones() {
+    let f = |row: usize, col: usize| f64::from(u32::try_from(row * 3 + col).unwrap()).sqrt();
+    //values computed one by one on the calling thread
+    let serial = DMat::from_vec(
+        300,
+        200,
+        (0..300 * 200)
+            .map(|index| f(index / 200, index % 200))
+            .collect(),
+    );
+    assert_eq!(DMat::par_generate(300, 200, f), serial);
+    assert_eq!(DMat::generate(300, 200, f), serial);
+
+    let mat = pseudo_random(300, 200, 1);
+    let squared = DMat::from_vec(
+        300,
+        200,
+        mat.as_slice().iter().map(|n| n * n - 1.0).collect(),
+    );
+    assert_eq!(mat.par_map(|n| n * n - 1.0), squared);
+    assert_eq!(mat.map(|n| n * n - 1.0), squared);
+
+    let (mut sequential, mut parallel) = (mat.clone(), mat.clone());
+    sequential.par_mutate(|n, row, col| n + f(row, col));
+    parallel.mutate(|n, row, col| n + f(row, col));
+    assert_eq!(parallel, sequential);
+    assert_eq!(parallel, &mat + &serial);
+}
+
+#[test]
+fn results_do_not_depend_on_the_number_of_threads() {
+    let a = pseudo_random(150, 120, 2);
+    let b = pseudo_random(120, 90, 3);
+    let square = pseudo_random(200, 200, 4);
+
+    let single = rayon::ThreadPoolBuilder::new()
+        .num_threads(1)
+        .build()
+        .unwrap();
+    let (product, transpose, determinant, inverse) = single.install(|| {
+        (
+            &a * &b,
+            a.transpose(),
+            square.determinant(),
+            square.inverse().unwrap(),
+        )
+    });
+
+    assert_eq!(&a * &b, product);
+    assert_eq!(a.transpose(), transpose);
+    assert_eq!(square.determinant().to_bits(), determinant.to_bits());
+    assert_eq!(square.inverse().unwrap(), inverse);
+
+    let f = |n: f64, row: usize, col: usize| (n * 7.0).sin() + (row * col) as f64;
+    let (mapped, mutated) = single.install(|| {
+        let mut mutated = square.clone();
+        mutated.mutate(f);
+        (square.map(f64::exp), mutated)
+    });
+    let mut parallel = square.clone();
+    parallel.mutate(f);
+    assert_eq!(square.map(f64::exp), mapped);
+    assert_eq!(parallel, mutated);
+}
+
+#[test]
+fn parallel_inverse_is_an_inverse() {
+    let mat = pseudo_random(200, 200, 5);
+    let identity = DMat::identity(200);
+    common::assert_close(&(&mat * &mat.inverse().unwrap()), &identity, 1e-9);
+}
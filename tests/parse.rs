@@ -0,0 +1,110 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use common::pseudo_random;
+use mat_rs::display::DisplayOptions;
+use mat_rs::dmat::{dmat, DMat};
+use mat_rs::mat::{mat, Mat};
+use mat_rs::parse::ParseMatError;
+
+#[test]
+fn matlab_literals() {
+    let expected = dmat![1.0, -2.5, 3e2; 4.0, 0.0, -6.0];
+    for input in [
+        "[1 -2.5 3e2; 4 0 -6]",
+        "[1, -2.5, 3e2; 4, 0, -6]",
+        "  [1 -2.5 3e2\n 4 0 -6]  ",
+        "[1 -2.5 3e2;\n4 0 -6;]",
+        "1 -2.5 300\n4 0 -6",
+    ] {
+        assert_eq!(input.parse::<DMat>(), Ok(expected.clone()), "{input}");
+    }
+    assert_eq!("[7]".parse::<Mat<1, 1>>(), Ok(mat![7]));
+}
+
+#[test]
+fn macro_syntax() {
+    let expected = mat![1, 2, 3; 4, 5, 6];
+    for input in [
+        "mat![1, 2, 3; 4, 5, 6]",
+        "dmat![1, 2, 3; 4, 5, 6]",
+        "mat! [1, 2, 3;\n      4, 5, 6]",
+        "1, 2, 3; 4, 5, 6",
+    ] {
+        assert_eq!(input.parse::<Mat<2, 3>>(), Ok(expected), "{input}");
+        assert_eq!(
+            input.parse::<DMat>(),
+            Ok(dmat![1, 2, 3; 4, 5, 6]),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn display_output_round_trips() {
+    let mat = mat![1.5, -2.0; 3.0, 4e10; -0.125, 0.0];
+    assert_eq!(mat.to_string().parse::<Mat<3, 2>>(), Ok(mat));
+    assert_eq!(format!("{mat:e}").parse::<Mat<3, 2>>(), Ok(mat));
+    assert_eq!(format!("{mat:>+12}").parse::<Mat<3, 2>>(), Ok(mat));
+
+    //single rows and columns
+    let row = dmat![1, 2, 3];
+    assert_eq!(row.to_string().parse::<DMat>(), Ok(row));
+    let col = dmat![1; 2];
+    assert_eq!(col.to_string().parse::<DMat>(), Ok(col));
+
+    //shortest round-trip representation of arbitrary values
+    let random = pseudo_random(6, 7, 1);
+    assert_eq!(random.to_string().parse::<DMat>(), Ok(random));
+}
+
+#[test]
+fn elided_display_output_is_rejected() {
+    let tall = DMat::generate(25, 2, |row, col| (row * 2 + col) as f64);
+    let wide = tall.transpose();
+    let both = DMat::generate(25, 25, |row, col| (row + col) as f64);
+    for mat in [&tall, &wide, &both] {
+        assert_eq!(mat.to_string().parse::<DMat>(), Err(ParseMatError::Elided));
+    }
+
+    let unlimited = tall.display_with(DisplayOptions::UNLIMITED).to_string();
+    assert_eq!(unlimited.parse::<DMat>(), Ok(tall));
+}
+
+#[test]
+fn invalid_input_is_an_error() {
+    assert_eq!("".parse::<DMat>(), Err(ParseMatError::Empty));
+    assert_eq!("[ ]".parse::<DMat>(), Err(ParseMatError::Empty));
+    assert_eq!(
+        "[1 2".parse::<DMat>(),
+        Err(ParseMatError::UnbalancedBrackets)
+    );
+    assert_eq!(
+        "[[1 2]]".parse::<DMat>(),
+        Err(ParseMatError::UnbalancedBrackets)
+    );
+    assert_eq!(
+        "[1 2; 3 x]".parse::<DMat>(),
+        Err(ParseMatError::InvalidValue { row: 2, column: 2 })
+    );
+    assert_eq!(
+        "1,, 2".parse::<DMat>(),
+        Err(ParseMatError::InvalidValue { row: 1, column: 2 })
+    );
+    assert_eq!(
+        "[1 2; 3 4 5]".parse::<DMat>(),
+        Err(ParseMatError::RaggedRow {
+            row: 2,
+            expected: 2,
+            found: 3
+        })
+    );
+    assert_eq!(
+        "[1 2; 3 4]".parse::<Mat<2, 3>>(),
+        Err(ParseMatError::WrongDimensions {
+            expected: (2, 3),
+            found: (2, 2)
+        })
+    );
+}
@@ -0,0 +1,90 @@
+use mat_rs::mat::Mat;
+use mat_rs::quaternion::{EulerOrder, Quaternion};
+use mat_rs::vector::{vector, Vector};
+
+const ORDERS: [EulerOrder; 12] = [
+    EulerOrder::XYZ,
+    EulerOrder::XZY,
+    EulerOrder::YXZ,
+    EulerOrder::YZX,
+    EulerOrder::ZXY,
+    EulerOrder::ZYX,
+    EulerOrder::XYX,
+    EulerOrder::XZX,
+    EulerOrder::YXY,
+    EulerOrder::YZY,
+    EulerOrder::ZXZ,
+    EulerOrder::ZYZ,
+];
+
+fn assert_close(a: f64, b: f64) {
+    assert!((a - b).abs() < 1e-9, "{a} != {b}");
+}
+
+//q and -q represent the same rotation
+fn assert_same_rotation(a: &Quaternion, b: &Quaternion) {
+    assert_close(a.dot(b).abs(), 1.0);
+}
+
+#[test]
+fn euler_round_trip() {
+    for order in ORDERS {
+        //the middle angle must lie in the range to_euler returns
+        let middle = if matches!(
+            order,
+            EulerOrder::XYZ
+                | EulerOrder::XZY
+                | EulerOrder::YXZ
+                | EulerOrder::YZX
+                | EulerOrder::ZXY
+                | EulerOrder::ZYX
+        ) {
+            -0.7
+        } else {
+            1.1
+        };
+        let angles = [0.3, middle, -2.5];
+        let quaternion = Quaternion::from_euler(order, angles);
+        let recovered = quaternion.to_euler(order);
+        for (angle, expected) in recovered.iter().zip(angles) {
+            assert_close(*angle, expected);
+        }
+        assert_same_rotation(&Quaternion::from_euler(order, recovered), &quaternion);
+    }
+}
+
+#[test]
+fn euler_gimbal_lock() {
+    for order in ORDERS {
+        for middle in [0.0, core::f64::consts::FRAC_PI_2, core::f64::consts::PI] {
+            let quaternion = Quaternion::from_euler(order, [0.4, middle, 0.9]);
+            let recovered = quaternion.to_euler(order);
+            assert_same_rotation(&Quaternion::from_euler(order, recovered), &quaternion);
+        }
+    }
+}
+
+#[test]
+fn intrinsic_euler_matches_matrix_product() {
+    let quaternion = Quaternion::from_euler(EulerOrder::ZYX, [0.5, -0.2, 1.3]);
+    let expected = Mat::rotation_z(0.5) * Mat::rotation_y(-0.2) * Mat::rotation_x(1.3);
+    let matrix = quaternion.to_homogeneous_matrix();
+    for row in 0..4 {
+        for col in 0..4 {
+            assert_close(matrix[row][col], expected[row][col]);
+        }
+    }
+}
+
+#[test]
+fn rotation_matrix_round_trip() {
+    let quaternion = Quaternion::from_axis_angle(vector![1.0, 2.0, -2.0], 2.9);
+    let recovered = Quaternion::from_rotation_matrix(&quaternion.to_rotation_matrix());
+    assert_same_rotation(&recovered, &quaternion);
+
+    let rotated = quaternion.rotate_vector(vector![0.5, -1.0, 3.0]);
+    let expected = quaternion.to_rotation_matrix() * vector![0.5, -1.0, 3.0];
+    for row in 0..3 {
+        assert_close(rotated[row][0], expected[row][0]);
+    }
+}
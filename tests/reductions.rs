@@ -0,0 +1,75 @@
+use mat_rs::mat::{mat, Mat};
+
+#[test]
+fn mat_reductions() {
+    let mat = mat![1, -2, 3; 4, 0, -6];
+    assert_eq!(mat.sum(), 0.0);
+    assert_eq!(mat.product(), 0.0);
+    assert_eq!(mat.mean(), 0.0);
+    assert_eq!(mat.min(), -6.0);
+    assert_eq!(mat.max(), 4.0);
+    assert_eq!(mat.argmin(), Some((1, 2)));
+    assert_eq!(mat.argmax(), Some((1, 0)));
+
+    assert_eq!(mat.sum_rows(), mat![2; -2]);
+    assert_eq!(mat.sum_cols(), mat![5, -2, -3]);
+    assert_eq!(mat.max_cols(), mat![4, 0, 3]);
+    assert_eq!(mat.argmin_rows(), [Some(1), Some(2)]);
+    assert_eq!(mat.argmax_cols(), [Some(1), Some(1), Some(0)]);
+}
+
+#[test]
+fn ties_give_the_first_index() {
+    let mat = mat![2, 5, 5; 5, 2, 2];
+    assert_eq!(mat.argmax(), Some((0, 1)));
+    assert_eq!(mat.argmin(), Some((0, 0)));
+    assert_eq!(mat.argmin_rows(), [Some(0), Some(1)]);
+}
+
+#[test]
+fn nan_values_propagate_or_are_skipped() {
+    let mat = mat![1.0, f64::NAN, -3.0; 2.0, 0.5, 4.0];
+    assert!(mat.sum().is_nan());
+    assert!(mat.min().is_nan());
+    assert_eq!(mat.argmin(), Some((0, 1)));
+    assert_eq!(mat.argmax_rows(), [Some(1), Some(2)]);
+
+    assert_eq!(mat.nan_sum(), 4.5);
+    assert_eq!(mat.nan_mean(), 0.9);
+    assert_eq!(mat.nan_min(), -3.0);
+    assert_eq!(mat.nan_argmin(), Some((0, 2)));
+    assert_eq!(mat.nan_argmax_rows(), [Some(0), Some(2)]);
+
+    let all_nan = mat![f64::NAN, f64::NAN];
+    assert!(all_nan.nan_max().is_nan());
+    assert!(all_nan.nan_mean().is_nan());
+    assert_eq!(all_nan.nan_sum(), 0.0);
+    assert_eq!(all_nan.nan_argmax(), None);
+}
+
+#[test]
+fn empty_reductions() {
+    let empty = Mat::<0, 3>::zero();
+    assert_eq!(empty.sum(), 0.0);
+    assert_eq!(empty.product(), 1.0);
+    assert!(empty.mean().is_nan());
+    assert!(empty.max().is_nan());
+    assert_eq!(empty.argmax(), None);
+    assert_eq!(empty.argmin_cols(), [None, None, None]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn dmat_reductions_match_mat() {
+    use mat_rs::dmat::DMat;
+
+    let values = [[1.0, f64::NAN, -3.0], [2.0, 0.5, 4.0]];
+    let mat = Mat::from(values);
+    let dmat = DMat::from(values);
+
+    assert_eq!(dmat.nan_sum_rows(), DMat::from(*mat.nan_sum_rows()));
+    assert_eq!(dmat.nan_min_cols(), DMat::from(*mat.nan_min_cols()));
+    assert_eq!(dmat.argmax(), mat.argmax());
+    assert_eq!(dmat.nan_argmin_cols(), mat.nan_argmin_cols().to_vec());
+    assert_eq!(dmat.nan_argmax_rows(), mat.nan_argmax_rows().to_vec());
+}
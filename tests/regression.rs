@@ -0,0 +1,261 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use common::{assert_close, pseudo_random};
+use mat_rs::dmat::{dmat, DMat};
+use mat_rs::regression::{LinearRegression, RidgeRegression};
+
+//20 observations of 3 features, the last being 0.1 times the first plus 0.3 times the second
+fn dependent_features() -> DMat {
+    let independent = pseudo_random(20, 2, 1);
+    DMat::generate(20, 3, |row, col| match col {
+        2 => 0.1 * independent[row][0] + 0.3 * independent[row][1],
+        _ => independent[row][col],
+    })
+}
+
+#[test]
+fn simple_regression_matches_the_closed_form() {
+    let x = pseudo_random(15, 1, 2);
+    let noise = pseudo_random(15, 1, 3);
+    let y = x.map(|n| 2.0 - 3.0 * n) + noise.map(|n| n * 0.1);
+    let model = LinearRegression::fit(&x, &y, true).unwrap();
+
+    let (x, y) = (x.as_slice(), y.as_slice());
+    let (mean_x, mean_y) = (x.iter().sum::<f64>() / 15.0, y.iter().sum::<f64>() / 15.0);
+    let sxx: f64 = x.iter().map(|x| (x - mean_x) * (x - mean_x)).sum();
+    let sxy: f64 = x
+        .iter()
+        .zip(y)
+        .map(|(x, y)| (x - mean_x) * (y - mean_y))
+        .sum();
+    let slope = sxy / sxx;
+    let intercept = mean_y - slope * mean_x;
+    let residual_variance = x
+        .iter()
+        .zip(y)
+        .map(|(x, y)| (y - intercept - slope * x) * (y - intercept - slope * x))
+        .sum::<f64>()
+        / 13.0;
+
+    assert!((model.coefficients()[0][0] - slope).abs() < 1e-13);
+    assert!((model.intercept()[0] - intercept).abs() < 1e-13);
+    let standard_error = (residual_variance / sxx).sqrt();
+    assert!((model.standard_errors()[0][0] - standard_error).abs() < 1e-13);
+    let intercept_standard_error =
+        (residual_variance * (1.0 / 15.0 + mean_x * mean_x / sxx)).sqrt();
+    assert!((model.intercept_standard_errors()[0] - intercept_standard_error).abs() < 1e-13);
+}
+
+#[test]
+fn intercept_matches_a_column_of_ones() {
+    let features = pseudo_random(25, 3, 4);
+    let targets = pseudo_random(25, 2, 5);
+    let weights: Vec<f64> = (0..25).map(|index| f64::from(index % 3) + 0.5).collect();
+    let model = LinearRegression::fit_weighted(&features, &targets, &weights, true).unwrap();
+
+    let augmented = DMat::generate(
+        25,
+        4,
+        |row, col| if col == 3 { 1.0 } else { features[row][col] },
+    );
+    let augmented_model =
+        LinearRegression::fit_weighted(&augmented, &targets, &weights, false).unwrap();
+
+    let slopes = DMat::generate(3, 2, |row, col| augmented_model.coefficients()[row][col]);
+    assert_close(model.coefficients(), &slopes, 1e-13);
+    let standard_errors =
+        DMat::generate(3, 2, |row, col| augmented_model.standard_errors()[row][col]);
+    assert_close(model.standard_errors(), &standard_errors, 1e-13);
+    for target in 0..2 {
+        let ones = 3;
+        assert!(
+            (model.intercept()[target] - augmented_model.coefficients()[ones][target]).abs()
+                < 1e-13
+        );
+        assert!(
+            (model.intercept_standard_errors()[target]
+                - augmented_model.standard_errors()[ones][target])
+                .abs()
+                < 1e-13
+        );
+    }
+    assert_close(
+        &model.predict(&features),
+        &augmented_model.predict(&augmented),
+        1e-13,
+    );
+}
+
+#[test]
+fn ridge_matches_the_closed_form() {
+    let features = pseudo_random(30, 4, 6);
+    let targets = pseudo_random(30, 1, 7);
+    let model = RidgeRegression::fit(&features, &targets, 2.5, false).unwrap();
+    assert!((model.alpha() - 2.5).abs() < f64::EPSILON);
+
+    let transpose = features.transpose();
+    let regularized = &transpose * &features + DMat::identity(4).map(|n| n * 2.5);
+    let expected = regularized.inverse().unwrap() * (transpose * &targets);
+    assert_close(model.coefficients(), &expected, 1e-14);
+}
+
+#[test]
+fn ridge_without_penalty_is_least_squares() {
+    let features = pseudo_random(30, 3, 8);
+    let targets = pseudo_random(30, 2, 9);
+    let ridge = RidgeRegression::fit(&features, &targets, 0.0, true).unwrap();
+    let linear = LinearRegression::fit(&features, &targets, true).unwrap();
+    assert_eq!(*ridge, *linear);
+}
+
+#[test]
+fn exact_data_is_fitted_exactly() {
+    let features = pseudo_random(10, 2, 10);
+    let targets = DMat::generate(10, 1, |row, _| {
+        1.5 * features[row][0] - 0.5 * features[row][1] + 4.0
+    });
+    let model = LinearRegression::fit(&features, &targets, true).unwrap();
+
+    assert_close(model.coefficients(), &dmat![1.5; -0.5], 1e-13);
+    assert!((model.intercept()[0] - 4.0).abs() < 1e-13);
+    assert!((model.r_squared()[0] - 1.0).abs() < 1e-13);
+    assert!(model
+        .standard_errors()
+        .as_slice()
+        .iter()
+        .all(|&n| n < 1e-13));
+}
+
+#[test]
+fn linearly_dependent_features_are_rejected() {
+    let features = dependent_features();
+    let targets = pseudo_random(20, 1, 11);
+    assert_eq!(LinearRegression::fit(&features, &targets, true), None);
+    assert_eq!(LinearRegression::fit(&features, &targets, false), None);
+
+    //a constant feature is a multiple of the intercept
+    let constant = DMat::generate(
+        20,
+        2,
+        |row, col| if col == 0 { features[row][0] } else { 3.0 },
+    );
+    assert_eq!(LinearRegression::fit(&constant, &targets, true), None);
+    assert!(LinearRegression::fit(&constant, &targets, false).is_some());
+
+    //but the penalty makes their coefficients unique
+    assert!(RidgeRegression::fit(&features, &targets, 0.1, true).is_some());
+}
+
+#[test]
+fn standard_errors_are_nan_without_degrees_of_freedom() {
+    //3 observations, 2 features and an intercept
+    let features = dmat![0, 1; 1, 0; 2, 3];
+    let targets = dmat![1; 2; 4];
+    let model = LinearRegression::fit(&features, &targets, true).unwrap();
+
+    assert_close(&model.predict(&features), &targets, 1e-13);
+    assert!(model
+        .standard_errors()
+        .as_slice()
+        .iter()
+        .all(|n| n.is_nan()));
+    assert!(model.intercept_standard_errors()[0].is_nan());
+}
+
+#[test]
+fn inverse_of_known_matrices() {
+    let inverse = dmat![4, 7; 2, 6].inverse().unwrap();
+    assert_close(&inverse, &dmat![0.6, -0.7; -0.2, 0.4], 1e-15);
+
+    let random = pseudo_random(6, 6, 12);
+    assert_close(
+        &(random.inverse().unwrap() * &random),
+        &DMat::identity(6),
+        1e-12,
+    );
+
+    assert_eq!(dmat![1, 2; 2, 4].inverse(), None);
+}
+
+#[test]
+fn nearly_collinear_features_are_fitted() {
+    //the second feature differs from the first by 1e-7 times noise, squaring the condition number
+    //of the features in the normal equations would leave no significant digits
+    let base = pseudo_random(40, 1, 13);
+    let noise = pseudo_random(40, 1, 14);
+    let features = DMat::generate(40, 2, |row, col| {
+        base[row][0] + if col == 1 { 1e-7 * noise[row][0] } else { 0.0 }
+    });
+    let targets = DMat::generate(40, 1, |row, _| {
+        2.0 * features[row][0] - features[row][1] + 1.0
+    });
+    let model = LinearRegression::fit(&features, &targets, true).unwrap();
+
+    assert_close(model.coefficients(), &dmat![2.0; -1.0], 1e-6);
+    assert!((model.intercept()[0] - 1.0).abs() < 1e-12);
+    assert_close(&model.predict(&features), &targets, 1e-13);
+}
+
+#[test]
+fn ridge_standard_errors_match_the_sandwich_covariance() {
+    let features = pseudo_random(30, 3, 15);
+    let targets = pseudo_random(30, 1, 16);
+    let weights: Vec<f64> = (0..30).map(|index| f64::from(index % 4) + 0.25).collect();
+    let model = RidgeRegression::fit_weighted(&features, &targets, &weights, 1.5, false).unwrap();
+
+    let weighted_transpose = DMat::generate(3, 30, |row, col| features[col][row] * weights[col]);
+    let gram = &weighted_transpose * &features;
+    let inverse = (&gram + &DMat::identity(3).map(|n| n * 1.5))
+        .inverse()
+        .unwrap();
+    let coefficients = &inverse * &(&weighted_transpose * &targets);
+    assert_close(model.coefficients(), &coefficients, 1e-14);
+
+    let residuals = &targets - &(&features * &coefficients);
+    let residual_variance = (0..30)
+        .map(|row| weights[row] * residuals[row][0] * residuals[row][0])
+        .sum::<f64>()
+        / 27.0;
+    let covariance = &inverse * &gram * &inverse;
+    let standard_errors = DMat::generate(3, 1, |row, _| {
+        (residual_variance * covariance[row][row]).sqrt()
+    });
+    assert_close(model.standard_errors(), &standard_errors, 1e-14);
+}
+
+#[test]
+fn r_squared_is_nan_for_a_constant_target() {
+    let features = pseudo_random(10, 2, 17);
+    let targets = DMat::generate(
+        10,
+        2,
+        |row, col| {
+            if col == 0 {
+                3.0
+            } else {
+                features[row][0]
+            }
+        },
+    );
+    let model = LinearRegression::fit(&features, &targets, true).unwrap();
+    assert!(model.r_squared()[0].is_nan());
+    assert!((model.r_squared()[1] - 1.0).abs() < 1e-13);
+    assert!((model.intercept()[0] - 3.0).abs() < 1e-13);
+
+    //R² is relative to the variance around the mean, with or without an intercept
+    let ridge = RidgeRegression::fit(&features, &targets, 0.5, false).unwrap();
+    assert!(ridge.r_squared()[0].is_nan());
+}
+
+#[test]
+#[should_panic(expected = "Attempted to fit a ridge regression with a negative penalty")]
+fn negative_penalty_panics() {
+    let _ = RidgeRegression::fit(
+        &pseudo_random(5, 2, 18),
+        &pseudo_random(5, 1, 19),
+        -1.0,
+        true,
+    );
+}
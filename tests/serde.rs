@@ -0,0 +1,87 @@
+#![cfg(feature = "serde")]
+
+use mat_rs::dmat::{dmat, DMat};
+use mat_rs::mat::{mat, Mat};
+
+#[test]
+fn mat_round_trip() {
+    let a = mat![1.5, -2.0, 3.0; 4.0, 0.0, -6.25];
+    let json = serde_json::to_string(&a).unwrap();
+    assert_eq!(json, "[[1.5,-2.0,3.0],[4.0,0.0,-6.25]]");
+    assert_eq!(serde_json::from_str::<Mat<2, 3>>(&json).unwrap(), a);
+
+    let empty = Mat::<0, 3>::zero();
+    assert_eq!(serde_json::to_string(&empty).unwrap(), "[]");
+    assert_eq!(serde_json::from_str::<Mat<0, 3>>("[]").unwrap(), empty);
+}
+
+#[test]
+fn mat_rejects_wrong_lengths() {
+    for json in [
+        "[[1,2,3]]",
+        "[[1,2,3],[4,5,6],[7,8,9]]",
+        "[[1,2,3],[4,5]]",
+        "[[1,2,3],[4,5,6,7]]",
+        "[1,2,3,4,5,6]",
+    ] {
+        assert!(serde_json::from_str::<Mat<2, 3>>(json).is_err(), "{json}");
+    }
+
+    let error = serde_json::from_str::<Mat<2, 3>>("[[1,2,3],[4,5,6,7]]").unwrap_err();
+    assert!(error.to_string().contains("a row of 3 numbers"), "{error}");
+}
+
+#[test]
+fn dmat_round_trip() {
+    let a = dmat![1.5, -2.0; 3.0, 4.0; 0.0, -6.25];
+    let json = serde_json::to_string(&a).unwrap();
+    assert_eq!(
+        json,
+        r#"{"rows":3,"cols":2,"data":[1.5,-2.0,3.0,4.0,0.0,-6.25]}"#
+    );
+    assert_eq!(serde_json::from_str::<DMat>(&json).unwrap(), a);
+
+    //fields in any order, or as a sequence
+    let reordered = r#"{"data":[1,2,3,4,5,6],"cols":3,"rows":2}"#;
+    assert_eq!(
+        serde_json::from_str::<DMat>(reordered).unwrap(),
+        dmat![1, 2, 3; 4, 5, 6]
+    );
+    let sequence = "[2,3,[1,2,3,4,5,6]]";
+    assert_eq!(
+        serde_json::from_str::<DMat>(sequence).unwrap(),
+        dmat![1, 2, 3; 4, 5, 6]
+    );
+
+    let empty = DMat::zero(0, 4);
+    let json = serde_json::to_string(&empty).unwrap();
+    assert_eq!(serde_json::from_str::<DMat>(&json).unwrap(), empty);
+}
+
+#[test]
+fn dmat_rejects_inconsistent_fields() {
+    for json in [
+        r#"{"rows":2,"cols":3,"data":[1,2,3,4,5]}"#,
+        r#"{"rows":2,"cols":3,"data":[1,2,3,4,5,6,7]}"#,
+        r#"{"rows":0,"cols":3,"data":[1]}"#,
+        r#"{"rows":18446744073709551615,"cols":2,"data":[]}"#,
+        r#"{"rows":2,"data":[1,2]}"#,
+        r#"{"rows":1,"cols":2,"cols":2,"data":[1,2]}"#,
+        r#"{"rows":1,"cols":2,"data":[1,2],"extra":0}"#,
+        "[2,3,[1,2,3]]",
+    ] {
+        assert!(serde_json::from_str::<DMat>(json).is_err(), "{json}");
+    }
+
+    let error =
+        serde_json::from_str::<DMat>(r#"{"rows":2,"cols":3,"data":[1,2,3,4,5]}"#).unwrap_err();
+    assert!(
+        error.to_string().contains("6 values for a 2x3 matrix"),
+        "{error}"
+    );
+    let error = serde_json::from_str::<DMat>(r#"{"rows":2,"data":[1,2]}"#).unwrap_err();
+    assert!(
+        error.to_string().contains("missing field `cols`"),
+        "{error}"
+    );
+}
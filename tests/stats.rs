@@ -0,0 +1,119 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use common::{assert_close, pseudo_random};
+use mat_rs::dmat::{dmat, DMat};
+use mat_rs::stats::RunningCovariance;
+
+#[test]
+fn covariance_and_correlation() {
+    let data = dmat![1, 2; 2, 4; 3, 5; 4, 9];
+    //means 2.5 and 5, deviations (-1.5, -3), (-0.5, -1), (0.5, 0), (1.5, 4)
+    assert_close(
+        &data.covariance(),
+        &dmat![5.0 / 3.0, 11.0 / 3.0; 11.0 / 3.0, 26.0 / 3.0],
+        1e-12,
+    );
+
+    let correlation = data.correlation();
+    let expected = 11.0 / 3.0 / (5.0_f64 / 3.0 * 26.0 / 3.0).sqrt();
+    assert_close(&correlation, &dmat![1.0, expected; expected, 1.0], 1e-12);
+
+    let standardized = data.standardize();
+    assert_close(&standardized.mean_cols(), &DMat::zero(1, 2), 1e-12);
+    assert_close(&standardized.covariance(), &correlation, 1e-12);
+}
+
+#[test]
+fn unit_weights_match_unweighted_statistics() {
+    let data = pseudo_random(20, 3, 1);
+    let weights = [1.0; 20];
+    assert_close(&data.weighted_mean(&weights), &data.mean_cols(), 1e-15);
+    assert_close(
+        &data.weighted_covariance(&weights),
+        &data.covariance(),
+        1e-15,
+    );
+    assert_close(
+        &data.weighted_correlation(&weights),
+        &data.correlation(),
+        1e-15,
+    );
+    assert_close(
+        &data.weighted_standardize(&weights),
+        &data.standardize(),
+        1e-13,
+    );
+}
+
+#[test]
+fn integer_weights_match_repeated_observations_means() {
+    let data = dmat![1, 5; 2, 3; 4, 0];
+    let repeated = dmat![1, 5; 2, 3; 2, 3; 4, 0; 4, 0; 4, 0];
+    assert_close(
+        &data.weighted_mean(&[1.0, 2.0, 3.0]),
+        &repeated.mean_cols(),
+        1e-15,
+    );
+}
+
+#[test]
+fn running_covariance_matches_batch_statistics() {
+    let data = pseudo_random(50, 4, 2);
+    let mut running = RunningCovariance::new(4);
+    running.push_rows(&data);
+
+    assert_eq!(running.count(), 50);
+    assert_close(&running.mean(), &data.mean_cols(), 1e-14);
+    assert_close(&running.covariance(), &data.covariance(), 1e-14);
+    assert_close(&running.correlation(), &data.correlation(), 1e-13);
+    assert_close(
+        &running.variance(),
+        &DMat::generate(1, 4, |_, col| data.covariance()[col][col]),
+        1e-14,
+    );
+}
+
+#[test]
+fn merged_accumulators_match_a_single_one() {
+    let data = pseudo_random(60, 3, 3);
+    let weights: Vec<f64> = (0..60)
+        .map(|index| f64::from(index % 4) * 0.5 + 0.25)
+        .collect();
+
+    let mut whole = RunningCovariance::new(3);
+    let mut parts = [
+        RunningCovariance::new(3),
+        RunningCovariance::new(3),
+        RunningCovariance::new(3),
+    ];
+    //uneven parts, including an empty one
+    for (index, (row, &weight)) in data.row_iter().zip(&weights).enumerate() {
+        whole.push_weighted(row, weight);
+        parts[if index < 10 { 0 } else { 2 }].push_weighted(row, weight);
+    }
+
+    let [mut merged, empty, rest] = parts;
+    merged.merge(&empty);
+    merged.merge(&rest);
+
+    assert_eq!(merged.count(), whole.count());
+    assert!((merged.weight_sum() - whole.weight_sum()).abs() < 1e-12);
+    assert_close(&merged.mean(), &whole.mean(), 1e-14);
+    assert_close(&merged.covariance(), &whole.covariance(), 1e-14);
+    assert_close(
+        &whole.covariance(),
+        &data.weighted_covariance(&weights),
+        1e-14,
+    );
+}
+
+#[test]
+fn running_covariance_is_stable_with_a_large_offset() {
+    //the naive sum of squares formula loses every digit of these variances
+    let data = pseudo_random(100, 2, 4).map(|n| n + 1e9);
+    let mut running = RunningCovariance::new(2);
+    running.push_rows(&data);
+    assert_close(&running.covariance(), &data.covariance(), 1e-7);
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use common::{assert_close, pseudo_random};
+use mat_rs::dmat::DMat;
+
+#[test]
+fn below_threshold_matches_blocked_product_exactly() {
+    let a = pseudo_random(20, 30, 1);
+    let b = pseudo_random(30, 25, 2);
+    assert_eq!(a.mul_strassen(&b, 32), &a * &b);
+    //one dimension below the threshold is enough to skip the recursion
+    let tall = pseudo_random(100, 10, 3);
+    let wide = pseudo_random(10, 100, 4);
+    assert_eq!(tall.mul_strassen(&wide, 16), &tall * &wide);
+}
+
+#[test]
+fn odd_sizes_match_blocked_product() {
+    for size in [17, 33, 65, 99] {
+        let a = pseudo_random(size, size, size as u64);
+        let b = pseudo_random(size, size, size as u64 + 1);
+        assert_close(&a.mul_strassen(&b, 16), &(&a * &b), 1e-12);
+    }
+}
+
+#[test]
+fn non_power_of_two_sizes_match_blocked_product() {
+    for (rows, inner, cols) in [(48, 80, 96), (50, 37, 61), (120, 40, 72)] {
+        let a = pseudo_random(rows, inner, 5);
+        let b = pseudo_random(inner, cols, 6);
+        let product = a.mul_strassen(&b, 16);
+        assert_eq!((product.rows(), product.cols()), (rows, cols));
+        assert_close(&product, &(&a * &b), 1e-12);
+    }
+}
+
+#[test]
+fn threshold_is_raised_to_16() {
+    let a = pseudo_random(40, 40, 7);
+    let b = pseudo_random(40, 40, 8);
+    assert_eq!(a.mul_strassen(&b, 0), a.mul_strassen(&b, 16));
+    assert_eq!(a.mul_strassen(&b, 1), a.mul_strassen(&b, 16));
+}
+
+#[test]
+fn empty_operands() {
+    let a = DMat::zero(0, 20);
+    let b = DMat::zero(20, 3);
+    assert_eq!(a.mul_strassen(&b, 16), DMat::zero(0, 3));
+    assert_eq!(
+        b.transpose().mul_strassen(&DMat::zero(20, 0), 16),
+        DMat::zero(3, 0)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Attempted to multiply two non-commutative matrices")]
+fn mismatched_dimensions_panic() {
+    let _ = DMat::zero(3, 4).mul_strassen(&DMat::zero(3, 4), 16);
+}
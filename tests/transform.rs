@@ -0,0 +1,90 @@
+use core::f64::consts::FRAC_PI_2;
+use mat_rs::mat::{mat, Mat};
+use mat_rs::transform::AffineDecomposition;
+use mat_rs::vector::{vector, Vector};
+
+fn assert_mat_close<const R: usize, const C: usize>(a: &Mat<R, C>, b: &Mat<R, C>) {
+    for row in 0..R {
+        for col in 0..C {
+            assert!(
+                (a[row][col] - b[row][col]).abs() < 1e-9,
+                "{a:?} != {b:?} at ({row}, {col})"
+            );
+        }
+    }
+}
+
+#[test]
+fn rotations_needing_a_pivot_are_rotations() {
+    //exact 90 degree rotations, with zeros on the diagonal
+    let about_z = mat![0, -1, 0; 1, 0, 0; 0, 0, 1];
+    let about_x = mat![1, 0, 0; 0, 0, -1; 0, 1, 0];
+    let cycle = mat![0, 0, 1; 1, 0, 0; 0, 1, 0];
+    for rotation in [about_z, about_x, cycle] {
+        assert!(rotation.is_rotation_within(1e-9));
+        assert!(Mat::from_linear(&rotation).is_rigid_within(1e-9));
+    }
+
+    assert!(Mat::<4, 4>::rotation_z(FRAC_PI_2).is_rigid_within(1e-9));
+    assert!(
+        (Mat::<4, 4>::translation(vector![1.0, 2.0, 3.0]) * Mat::rotation_y(FRAC_PI_2))
+            .is_rigid_within(1e-9)
+    );
+}
+
+#[test]
+fn reflections_and_scales_are_not_rotations() {
+    let reflection = mat![0, 1, 0; 1, 0, 0; 0, 0, 1];
+    assert!(reflection.is_orthogonal_within(1e-9));
+    assert!(!reflection.is_rotation_within(1e-9));
+
+    let scaled = Mat::<4, 4>::rotation_z(FRAC_PI_2) * Mat::<4, 4>::scaling(vector![2.0, 1.0, 1.0]);
+    assert!(!scaled.is_rigid_within(1e-9));
+}
+
+#[test]
+fn decompose_affine_round_trip() {
+    let decomposition = AffineDecomposition {
+        translation: vector![1.0, -2.0, 0.5],
+        rotation: Mat::rotation_axis_angle(vector![1.0, 1.0, 0.0], 1.2).linear(),
+        scale: vector![2.0, 0.5, -3.0],
+        shear: vector![0.1, -0.2, 0.3],
+    };
+    let transform = decomposition.to_matrix();
+    let recovered = transform.decompose_affine().unwrap();
+
+    assert_mat_close(&recovered.to_matrix(), &transform);
+    assert_mat_close(&recovered.rotation, &decomposition.rotation);
+    assert_mat_close(&recovered.scale, &decomposition.scale);
+    assert_mat_close(&recovered.shear, &decomposition.shear);
+    assert_mat_close(&recovered.translation, &decomposition.translation);
+}
+
+#[test]
+fn orthonormalize_drifting_rotation() {
+    //a rotation followed by a small symmetric stretch, whose closest rotation is the original one
+    let drifted = Mat::<4, 4>::rotation_z(FRAC_PI_2)
+        * Mat::from([
+            [1.0 + 1e-4, 2e-4, 0.0, 0.0],
+            [2e-4, 1.0 - 1e-4, 0.0, 0.0],
+            [0.0, 0.0, 1.0 + 3e-4, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+    assert!(!drifted.is_rigid_within(1e-9));
+
+    let orthonormalized = drifted.orthonormalize_rotation().unwrap();
+    assert!(orthonormalized.is_rigid_within(1e-9));
+    assert_mat_close(&orthonormalized, &Mat::rotation_z(FRAC_PI_2));
+    assert!(drifted
+        .linear()
+        .orthonormalize()
+        .unwrap()
+        .is_rotation_within(1e-9));
+}
+
+#[test]
+fn singular_linear_block_does_not_decompose() {
+    let singular = Mat::<4, 4>::scaling(vector![1.0, 0.0, 1.0]);
+    assert!(singular.decompose_affine().is_none());
+    assert!(singular.orthonormalize_rotation().is_none());
+}
@@ -0,0 +1,150 @@
+use core::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+use mat_rs::mat::{mat, Mat};
+use mat_rs::vector::{vector, Vector};
+
+fn assert_close(a: f64, b: f64) {
+    assert!((a - b).abs() < 1e-12, "{a} != {b}");
+}
+
+#[test]
+fn products_and_norms() {
+    let a = vector![1.0, 2.0, 3.0];
+    let b = vector![4.0, -5.0, 6.0];
+
+    assert_close(a.dot(&b), 12.0);
+    assert_eq!(a.cross(&b), vector![27.0, 6.0, -13.0]);
+    assert_close(a.cross(&b).dot(&a), 0.0);
+    assert_eq!(
+        vector![1.0, 0.0, 0.0].cross(&vector![0.0, 1.0, 0.0]),
+        vector![0.0, 0.0, 1.0]
+    );
+    assert_eq!(a.outer(&vector![1.0, -1.0]), mat![1, -1; 2, -2; 3, -3]);
+
+    let v = vector![3.0, -4.0];
+    assert_close(v.norm_squared(), 25.0);
+    assert_close(v.norm(), 5.0);
+    assert_close(v.norm_l1(), 7.0);
+    assert_close(v.norm_inf(), 4.0);
+    assert_eq!(v.to_array(), [3.0, -4.0]);
+}
+
+#[test]
+fn normalize() {
+    let unit = vector![3.0, 0.0, -4.0].normalize().unwrap();
+    assert_eq!(unit, vector![0.6, 0.0, -0.8]);
+    assert_close(unit.norm(), 1.0);
+
+    assert_eq!(Vector::<3>::zero().normalize(), None);
+}
+
+#[test]
+fn angles_and_projections() {
+    let x = vector![1.0, 0.0];
+    assert_close(x.angle(&vector![0.0, 2.0]), FRAC_PI_2);
+    assert_close(x.angle(&vector![3.0, 3.0]), FRAC_PI_4);
+    //parallel and antiparallel vectors, whose cosine may round past 1
+    let v = vector![0.1, 0.7];
+    assert_close(v.angle(&(v * 3.0)), 0.0);
+    assert_close(v.angle(&-v), PI);
+    assert!(x.angle(&Vector::zero()).is_nan());
+
+    assert_eq!(
+        vector![2.0, 5.0].project_onto(&vector![0.0, 3.0]),
+        vector![0.0, 5.0]
+    );
+    let projection = vector![1.0, 2.0, 3.0].project_onto(&vector![1.0, 1.0, 0.0]);
+    assert_eq!(projection, vector![1.5, 1.5, 0.0]);
+    assert!(x
+        .project_onto(&Vector::zero())
+        .iter()
+        .all(|n| n[0].is_nan()));
+}
+
+#[test]
+fn matrix_vector_product() {
+    let a = mat![1, 2, 3; 4, 5, 6];
+    let product: Vector<2> = a * vector![1.0, 0.0, -1.0];
+    assert_eq!(product, vector![-2.0, -2.0]);
+    assert_eq!(
+        Mat::<3, 3>::identity() * vector![7.0, 8.0, 9.0],
+        vector![7.0, 8.0, 9.0]
+    );
+}
+
+#[cfg(feature = "alloc")]
+mod dynamic {
+    use super::assert_close;
+    use core::f64::consts::FRAC_PI_2;
+    use mat_rs::dmat::{dmat, DMat};
+    use mat_rs::vector::{dvector, vector, DVector, Vector};
+
+    #[test]
+    fn products_and_norms() {
+        let a = dvector![1.0, 2.0, 3.0];
+        let b = dvector![4.0, -5.0, 6.0];
+
+        assert_close(a.dot(&b), 12.0);
+        assert_eq!(a.cross(&b), dvector![27.0, 6.0, -13.0]);
+        assert_eq!(a.outer(&dvector![1.0, -1.0]), dmat![1, -1; 2, -2; 3, -3]);
+
+        let v = dvector![3.0, -4.0];
+        assert_close(v.norm(), 5.0);
+        assert_close(v.norm_l1(), 7.0);
+        assert_close(v.norm_inf(), 4.0);
+        assert_eq!(v.len(), 2);
+        assert!(DVector::zero(0).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Attempted to take dot product of two vectors of different lengths")]
+    fn dot_of_different_lengths() {
+        let _ = dvector![1.0, 2.0].dot(&dvector![1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Attempted to take cross product of vectors that are not 3-dimensional"
+    )]
+    fn cross_of_2d_vectors() {
+        let _ = dvector![1.0, 2.0].cross(&dvector![3.0, 4.0]);
+    }
+
+    #[test]
+    fn normalize_angle_and_projection() {
+        assert_eq!(
+            dvector![3.0, 0.0, -4.0].normalize(),
+            Some(dvector![0.6, 0.0, -0.8])
+        );
+        assert_eq!(DVector::zero(3).normalize(), None);
+
+        assert_close(dvector![1.0, 0.0].angle(&dvector![0.0, 2.0]), FRAC_PI_2);
+        assert_eq!(
+            dvector![2.0, 5.0].project_onto(&dvector![0.0, 3.0]),
+            dvector![0.0, 5.0]
+        );
+    }
+
+    #[test]
+    fn matrix_vector_product() {
+        let a = dmat![1, 2, 3; 4, 5, 6];
+        assert_eq!(&a * &dvector![1.0, 0.0, -1.0], dvector![-2.0, -2.0]);
+        assert_eq!(a * dvector![0.0, 1.0, 0.0], dvector![2.0, 5.0]);
+    }
+
+    #[test]
+    fn conversions() {
+        let column = dmat![1; 2; 3];
+        let vector = DVector::try_from(column.clone()).unwrap();
+        assert_eq!(vector, DVector::from(vector![1.0, 2.0, 3.0]));
+        assert_eq!(DMat::from(vector), column);
+
+        //a matrix with more than one column is handed back
+        let row = dmat![1, 2, 3];
+        assert_eq!(DVector::try_from(row.clone()), Err(row));
+        let empty = DMat::zero(3, 0);
+        assert_eq!(DVector::try_from(empty.clone()), Err(empty));
+
+        let from_vector: DVector = Vector::<2>::from_array([4, 5]).into();
+        assert_eq!(from_vector.as_slice(), &[4.0, 5.0]);
+    }
+}